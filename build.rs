@@ -33,6 +33,8 @@ const LINUX_SRCS: &[&str] = &[
 
 const MOCK_LINUX_SRCS: &[&str] = &["src/linux/mockfile.c"];
 
+const MOCK_X86_SRCS: &[&str] = &["src/x86/mockcpuid.c"];
+
 const MACH_SRCS: &[&str] = &["src/mach/topology.c"];
 
 const FREEBSD_SRCS: &[&str] = &["src/freebsd/topology.c"];
@@ -110,15 +112,36 @@ fn main() {
         "x86_64-pc-windows-msvc" | "x86_64-pc-windows-gnu" => &[COMMON_SRCS, X86_SRCS, WINDOWS_X86_SRCS],
         "x86_64-unknown-freebsd" => &[COMMON_SRCS, X86_SRCS, FREEBSD_SRCS, FREEBSD_X86_SRCS],
         "x86_64-unknown-linux-gnu" | "x86_64-unknown-linux-musl" => &[COMMON_SRCS, X86_SRCS, LINUX_SRCS, LINUX_X86_SRCS],
-        _ => panic!("Unsupported platform {target}"),
+        _ => &[],
     };
 
-    let source_files: Vec<String> = sources
+    println!("cargo:rustc-check-cfg=cfg(cpuinfo_fallback)");
+
+    // Targets that `cpuinfo` can't be built for use the best-effort implementation in
+    // src/fallback.rs instead, which doesn't need the `cpuinfo` submodule at all.
+    if cfg!(feature = "fallback") || sources.is_empty() {
+        println!("cargo:rustc-cfg=cpuinfo_fallback");
+        return;
+    }
+
+    let mut source_files: Vec<String> = sources
         .iter()
         .flat_map(|i| i.iter())
         .map(|s| format!("{base}/{s}"))
         .collect();
 
+    // Replaces reads from `/proc` and `/sys` (and `cpuid` on x86) with data provided through
+    // `cpuinfo_rs::MockSystem`, mirroring the `cpuinfo_mock` target of the upstream CMake build.
+    if cfg!(feature = "mock") && target.contains("-linux-") {
+        let mut mock_sources = MOCK_LINUX_SRCS.to_vec();
+        if target.starts_with("x86_64-") || target.starts_with("i686-") {
+            mock_sources.extend_from_slice(MOCK_X86_SRCS);
+        }
+
+        source_files.extend(mock_sources.iter().map(|s| format!("{base}/{s}")));
+        build.define("CPUINFO_MOCK", "1");
+    }
+
     for source_file in &source_files {
         build.file(source_file);
     }
@@ -134,9 +157,253 @@ fn main() {
 
     build.compile("cpuinfo");
 
+    emit_cpuinfo_revision(base);
     generate_bindings(target.as_str());
 }
 
+/// `cpuinfo` doesn't have releases, so the commit of the submodule is recorded in snapshots to
+/// identify the version of the library.
+fn emit_cpuinfo_revision(base: &str) {
+    let revision = std::path::Path::new(base)
+        .join(".git")
+        .exists()
+        .then(|| {
+            std::process::Command::new("git")
+                .args(["-C", base, "rev-parse", "HEAD"])
+                .output()
+                .ok()
+        })
+        .flatten()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|revision| revision.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=CPUINFO_REVISION={revision}");
+}
+
+// Human-readable names that can't be derived from the identifier of an enum value.
+const VENDOR_NAMES: &[(&str, &str)] = &[
+    ("amd", "AMD"),
+    ("arm", "ARM"),
+    ("mips", "MIPS"),
+    ("ibm", "IBM"),
+    ("via", "VIA"),
+    ("apm", "Applied Micro"),
+    ("sifive", "SiFive"),
+    ("rdc", "RDC"),
+    ("dmp", "DM&P"),
+    ("nsc", "National Semiconductor"),
+    ("sis", "SiS"),
+    ("nexgen", "NexGen"),
+    ("umc", "UMC"),
+    ("dec", "DEC"),
+];
+
+const UARCH_NAMES: &[(&str, &str)] = &[
+    ("arm7", "ARM7"),
+    ("arm9", "ARM9"),
+    ("arm11", "ARM11"),
+    ("xscale", "XScale"),
+    ("pj4", "PJ4"),
+    ("thunderx", "ThunderX"),
+    ("thunderx2", "ThunderX2"),
+    ("xgene", "X-Gene"),
+    ("taishan_v110", "TaiShan v110"),
+];
+
+struct EnumValue {
+    ident: String,
+    value: u32,
+    doc: String,
+}
+
+/// Generates `VendorKind` and `UarchKind` from the C enums, so that values added upstream are
+/// picked up without having to maintain a list by hand. Like the bindings, the output is checked
+/// in so that builds without the `cpuinfo` submodule work.
+fn generate_enums(header: &str) {
+    let header = std::fs::read_to_string(header).expect("Unable to read cpuinfo.h");
+
+    let vendor = generate_enum(
+        &header,
+        "cpuinfo_vendor",
+        "VendorKind",
+        "Vendor of a CPU microarchitecture.",
+        |ident| name_from_table(VENDOR_NAMES, ident).unwrap_or_else(|| capitalize_words(ident)),
+    );
+    std::fs::write("src/vendor_kind.rs", vendor).expect("Unable to write vendor_kind.rs");
+
+    let uarch = generate_enum(
+        &header,
+        "cpuinfo_uarch",
+        "UarchKind",
+        "CPU microarchitecture.",
+        |ident| name_from_table(UARCH_NAMES, ident).unwrap_or_else(|| uarch_name(ident)),
+    );
+    std::fs::write("src/uarch_kind.rs", uarch).expect("Unable to write uarch_kind.rs");
+}
+
+fn generate_enum(
+    header: &str,
+    c_name: &str,
+    rust_name: &str,
+    doc: &str,
+    name: impl Fn(&str) -> String,
+) -> String {
+    let prefix = format!("{c_name}_");
+    let mut out = format!(
+        "// Generated by build.rs from `enum {c_name}` in cpuinfo.h, do not edit.\n\
+         // Regenerate with `cargo build --features generate-bindings`.\n\n\
+         cpuinfo_enum! {{\n    /// {doc}\n    pub enum {rust_name} {{\n"
+    );
+    let mut seen = vec![];
+
+    for value in parse_enum(header, c_name) {
+        // Aliases such as `cpuinfo_uarch_kaby_lake` share the value of an earlier entry.
+        if seen.contains(&value.value) {
+            continue;
+        }
+        seen.push(value.value);
+
+        let ident = value.ident.strip_prefix(&prefix).unwrap_or(&value.ident);
+        let variant = ident.split('_').map(capitalize).collect::<String>();
+
+        for line in value.doc.lines() {
+            out += &format!("        #[doc = {:?}]\n", format!(" {line}"));
+        }
+        out += &format!(
+            "        {variant} = {:#010x}, {:?};\n",
+            value.value,
+            name(ident)
+        );
+    }
+
+    out += "    }\n}\n";
+    out
+}
+
+/// Parses the values and doc comments of `enum {name}` from a C header.
+fn parse_enum(header: &str, name: &str) -> Vec<EnumValue> {
+    let start = header
+        .find(&format!("enum {name} {{"))
+        .unwrap_or_else(|| panic!("Unable to find enum {name} in cpuinfo.h"));
+    let body = &header[start..];
+    let body = &body[body.find('{').unwrap() + 1..body.find("};").unwrap()];
+
+    let mut values: Vec<EnumValue> = vec![];
+    let mut doc = String::new();
+    let mut rest = body;
+
+    loop {
+        rest = rest.trim_start();
+
+        if rest.is_empty() {
+            break;
+        } else if let Some(comment) = rest.strip_prefix("/**") {
+            let end = comment.find("*/").unwrap();
+            doc = comment[..end]
+                .lines()
+                .map(|line| line.trim().trim_start_matches('*').trim())
+                .skip_while(|line| line.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n")
+                .trim_end()
+                .to_string();
+            rest = &comment[end + 2..];
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = &comment[comment.find("*/").unwrap() + 2..];
+        } else if let Some(comment) = rest.strip_prefix("//") {
+            rest = comment.find('\n').map_or("", |end| &comment[end..]);
+        } else {
+            let end = rest.find(',').unwrap_or(rest.len());
+            let entry = rest[..end].trim();
+            rest = rest[end..].strip_prefix(',').unwrap_or("");
+
+            let (ident, value) = match entry.split_once('=') {
+                Some((ident, value)) => (ident.trim(), value.trim()),
+                None => (entry, ""),
+            };
+
+            let value = if value.is_empty() {
+                values.last().map_or(0, |previous| previous.value + 1)
+            } else if let Some(hex) = value.strip_prefix("0x").or(value.strip_prefix("0X")) {
+                u32::from_str_radix(hex, 16).unwrap()
+            } else if let Ok(value) = value.parse() {
+                value
+            } else {
+                values
+                    .iter()
+                    .find(|previous| previous.ident == value)
+                    .unwrap_or_else(|| panic!("Unable to evaluate {ident} = {value}"))
+                    .value
+            };
+
+            values.push(EnumValue {
+                ident: ident.to_string(),
+                value,
+                doc: std::mem::take(&mut doc),
+            });
+        }
+    }
+
+    values
+}
+
+fn name_from_table(table: &[(&str, &str)], ident: &str) -> Option<String> {
+    table
+        .iter()
+        .find(|(key, _)| *key == ident)
+        .map(|(_, name)| name.to_string())
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+fn capitalize_words(ident: &str) -> String {
+    if ident == "unknown" {
+        return ident.to_string();
+    }
+
+    ident
+        .split('_')
+        .map(capitalize)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Derives names like `Cortex-A55`, `Zen 2`, `K10` and `Exynos M1` from uarch identifiers.
+fn uarch_name(ident: &str) -> String {
+    if ident == "unknown" {
+        return ident.to_string();
+    }
+
+    if let Some(model) = ident.strip_prefix("cortex_") {
+        return format!("Cortex-{}", capitalize(model));
+    }
+
+    ident
+        .split('_')
+        .map(|word| {
+            let digits = word.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+            let letters = &word[..word.len() - digits.len()];
+
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                capitalize(word)
+            } else if letters.len() <= 2 {
+                word.to_ascii_uppercase()
+            } else {
+                format!("{} {digits}", capitalize(letters))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[cfg(feature = "generate-bindings")]
 fn generate_bindings(target: &str) {
     let dest = format!("src/bindings_{target}.rs");
@@ -160,6 +427,8 @@ fn generate_bindings(target: &str) {
     bindings
         .write_to_file(dest)
         .expect("Unable to write bindings!");
+
+    generate_enums("vendor/cpuinfo/include/cpuinfo.h");
 }
 
 #[cfg(not(feature = "generate-bindings"))]
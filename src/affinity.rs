@@ -0,0 +1,300 @@
+use std::io;
+
+use crate::{Cluster, Core, Processor, Topology};
+
+/// Set of logical processors that a thread can be pinned to, identified by the numbering of the
+/// operating system.
+///
+/// ```no_run
+/// use cpuinfo_rs::{CpuInfo, CpuSet};
+///
+/// let info = CpuInfo::new();
+/// let topology = info.topology();
+///
+/// // Allow the thread to run on either hyperthread of the first core.
+/// let set = CpuSet::from_core(topology, &topology.cores()[0]);
+/// set.pin_current_thread().unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CpuSet {
+    /// Sorted [`Processor::linux_id`]s.
+    linux_ids: Vec<i32>,
+    /// Sorted [`Processor::windows_group_id`]s and [`Processor::windows_processor_id`]s.
+    windows_ids: Vec<(u16, u16)>,
+}
+
+impl CpuSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Logical processors (hardware threads) of `core`, see [`Topology::core_processors()`].
+    pub fn from_core(topology: &Topology, core: &Core) -> Self {
+        topology.core_processors(core).iter().collect()
+    }
+
+    /// Logical processors in `cluster`, see [`Topology::cluster_processors()`].
+    pub fn from_cluster(topology: &Topology, cluster: &Cluster) -> Self {
+        topology.cluster_processors(cluster).iter().collect()
+    }
+
+    /// Set of the given [`Processor::linux_id`]s.
+    pub(crate) fn from_linux_ids(mut linux_ids: Vec<i32>) -> Self {
+        linux_ids.sort_unstable();
+        linux_ids.dedup();
+
+        Self {
+            linux_ids,
+            windows_ids: vec![],
+        }
+    }
+
+    /// Logical processors that the current thread is allowed to run on.
+    ///
+    /// Returns [`io::ErrorKind::Unsupported`] on platforms other than Linux and Android.
+    pub fn of_current_thread() -> io::Result<Self> {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let result = sys::affinity().map(Self::from_linux_ids);
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let result = Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "reading the thread affinity is not supported on this platform",
+        ));
+
+        result
+    }
+
+    /// Adds `processor` to the set, processors without an identifier for the current operating
+    /// system are ignored.
+    pub fn insert(&mut self, processor: &Processor) {
+        if let Some(id) = processor.linux_id {
+            if let Err(i) = self.linux_ids.binary_search(&id) {
+                self.linux_ids.insert(i, id);
+            }
+        }
+
+        if let (Some(group), Some(id)) =
+            (processor.windows_group_id, processor.windows_processor_id)
+        {
+            if let Err(i) = self.windows_ids.binary_search(&(group, id)) {
+                self.windows_ids.insert(i, (group, id));
+            }
+        }
+    }
+
+    pub fn contains(&self, processor: &Processor) -> bool {
+        let linux = processor
+            .linux_id
+            .is_some_and(|id| self.linux_ids.binary_search(&id).is_ok());
+        let windows = processor
+            .windows_group_id
+            .zip(processor.windows_processor_id)
+            .is_some_and(|id| self.windows_ids.binary_search(&id).is_ok());
+
+        linux || windows
+    }
+
+    /// Sorted [`Processor::linux_id`]s in the set.
+    pub fn linux_ids(&self) -> &[i32] {
+        &self.linux_ids
+    }
+
+    /// Number of logical processors in the set.
+    pub fn len(&self) -> usize {
+        self.linux_ids.len().max(self.windows_ids.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Restricts the current thread to the logical processors in this set.
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] for an empty set, for processor IDs that the
+    /// operating system can't have reported, such as negative Linux IDs from an edited snapshot,
+    /// or on Windows when the set spans more than one processor group, and
+    /// [`io::ErrorKind::Unsupported`] on platforms without thread affinity such as macOS.
+    pub fn pin_current_thread(&self) -> io::Result<()> {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let result = sys::set_affinity(&self.linux_ids);
+        #[cfg(target_os = "windows")]
+        let result = sys::set_affinity(&self.windows_ids);
+        #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "windows")))]
+        let result = Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "thread affinity is not supported on this platform",
+        ));
+
+        result
+    }
+}
+
+impl<'a> FromIterator<&'a Processor> for CpuSet {
+    fn from_iter<I: IntoIterator<Item = &'a Processor>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> Extend<&'a Processor> for CpuSet {
+    fn extend<I: IntoIterator<Item = &'a Processor>>(&mut self, iter: I) {
+        for processor in iter {
+            self.insert(processor);
+        }
+    }
+}
+
+impl From<&Processor> for CpuSet {
+    fn from(processor: &Processor) -> Self {
+        std::iter::once(processor).collect()
+    }
+}
+
+impl Processor {
+    /// Restricts the current thread to run on this logical processor only, see
+    /// [`CpuSet::pin_current_thread()`].
+    pub fn pin_current_thread(&self) -> io::Result<()> {
+        CpuSet::from(self).pin_current_thread()
+    }
+}
+
+impl Topology {
+    /// Runs `f` on the first logical processor of every core, or returns [`None`] if the thread
+    /// can't be pinned to one of them.
+    ///
+    /// This uses a separate thread, to leave the affinity of the calling thread untouched.
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    pub(crate) fn run_on_cores<T: Send>(&self, f: impl Fn() -> T + Sync) -> Option<Vec<T>> {
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    self.cores()
+                        .iter()
+                        .map(|core| {
+                            self.core_processors(core)[0].pin_current_thread().ok()?;
+                            Some(f())
+                        })
+                        .collect()
+                })
+                .join()
+                .ok()
+                .flatten()
+        })
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod sys {
+    use std::io;
+
+    const EINVAL: i32 = 22;
+
+    extern "C" {
+        fn sched_setaffinity(pid: i32, cpusetsize: usize, mask: *const u64) -> i32;
+        fn sched_getaffinity(pid: i32, cpusetsize: usize, mask: *mut u64) -> i32;
+    }
+
+    pub(super) fn affinity() -> io::Result<Vec<i32>> {
+        // The kernel rejects masks that are smaller than its own, so the mask is grown until it
+        // fits every processor that the kernel supports.
+        let mut mask = vec![0u64; 16];
+        while unsafe { sched_getaffinity(0, mask.len() * 8, mask.as_mut_ptr()) } != 0 {
+            let error = io::Error::last_os_error();
+            if error.raw_os_error() != Some(EINVAL) || mask.len() >= 1 << 16 {
+                return Err(error);
+            }
+            mask.resize(mask.len() * 2, 0);
+        }
+
+        Ok((0..mask.len() * 64)
+            .filter(|&id| mask[id / 64] & (1 << (id % 64)) != 0)
+            .map(|id| id as i32)
+            .collect())
+    }
+
+    pub(super) fn set_affinity(linux_ids: &[i32]) -> io::Result<()> {
+        let (Some(&first), Some(&last)) = (linux_ids.first(), linux_ids.last()) else {
+            return Err(io::ErrorKind::InvalidInput.into());
+        };
+        if first < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "CpuSet contains a negative Linux processor ID",
+            ));
+        }
+
+        // Sized to fit the highest ID, as `cpu_set_t` only holds 1024 processors.
+        let mut mask = vec![0u64; last as usize / 64 + 1];
+        for &id in linux_ids {
+            mask[id as usize / 64] |= 1 << (id % 64);
+        }
+
+        // A pid of 0 refers to the calling thread.
+        if unsafe { sched_setaffinity(0, mask.len() * 8, mask.as_ptr()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod sys {
+    use std::ffi::c_void;
+    use std::io;
+
+    #[repr(C)]
+    #[allow(non_camel_case_types)]
+    struct GROUP_AFFINITY {
+        mask: usize,
+        group: u16,
+        reserved: [u16; 3],
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetCurrentThread() -> *mut c_void;
+        fn SetThreadGroupAffinity(
+            thread: *mut c_void,
+            affinity: *const GROUP_AFFINITY,
+            previous: *mut GROUP_AFFINITY,
+        ) -> i32;
+    }
+
+    pub(super) fn set_affinity(windows_ids: &[(u16, u16)]) -> io::Result<()> {
+        let Some(&(group, _)) = windows_ids.first() else {
+            return Err(io::ErrorKind::InvalidInput.into());
+        };
+
+        // A thread can only be assigned to processors within a single group.
+        if windows_ids.iter().any(|&(other, _)| other != group) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "processors in a CpuSet span multiple processor groups",
+            ));
+        }
+        if windows_ids.iter().any(|&(_, id)| id as u32 >= usize::BITS) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "CpuSet contains a processor ID outside of the group affinity mask",
+            ));
+        }
+
+        let affinity = GROUP_AFFINITY {
+            mask: windows_ids
+                .iter()
+                .fold(0, |mask, &(_, id)| mask | 1usize << id),
+            group,
+            reserved: [0; 3],
+        };
+
+        if unsafe { SetThreadGroupAffinity(GetCurrentThread(), &affinity, std::ptr::null_mut()) }
+            == 0
+        {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitOrAssign};
+
+/// Binary characteristics of a [`crate::Cache`].
+///
+/// Serializes as a list of flag names, for example `["unified", "inclusive"]`, in human-readable
+/// formats and as the raw bits in binary formats. Bits that are not known to this crate are kept
+/// as a hexadecimal string such as `"0x10"` in the list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CacheFlags(u32);
+
+impl CacheFlags {
+    /// The cache holds both instructions and data (`CPUINFO_CACHE_UNIFIED`).
+    pub const UNIFIED: Self = Self(1);
+    /// The cache is inclusive of the lower cache levels (`CPUINFO_CACHE_INCLUSIVE`).
+    pub const INCLUSIVE: Self = Self(2);
+    /// The cache uses a complex function to map addresses to sets
+    /// (`CPUINFO_CACHE_COMPLEX_INDEXING`).
+    pub const COMPLEX_INDEXING: Self = Self(4);
+
+    const NAMES: &'static [(Self, &'static str)] = &[
+        (Self::UNIFIED, "unified"),
+        (Self::INCLUSIVE, "inclusive"),
+        (Self::COMPLEX_INDEXING, "complex_indexing"),
+    ];
+
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Creates flags from the raw `cpuinfo` bits, keeping bits that are not known to this crate.
+    pub const fn from_bits_retain(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Raw `cpuinfo` bits.
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all flags in `other` are set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn is_unified(self) -> bool {
+        self.contains(Self::UNIFIED)
+    }
+
+    pub const fn is_inclusive(self) -> bool {
+        self.contains(Self::INCLUSIVE)
+    }
+
+    pub const fn has_complex_indexing(self) -> bool {
+        self.contains(Self::COMPLEX_INDEXING)
+    }
+
+    /// Names of the known flags that are set.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        Self::NAMES
+            .iter()
+            .filter(move |(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+    }
+
+    /// Bits that don't have a name in [`Self::NAMES`].
+    const fn unknown_bits(self) -> u32 {
+        self.0 & !(Self::UNIFIED.0 | Self::INCLUSIVE.0 | Self::COMPLEX_INDEXING.0)
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .find(|(_, flag_name)| *flag_name == name)
+            .map(|(flag, _)| *flag)
+            .or_else(|| {
+                let bits = name.strip_prefix("0x")?;
+                u32::from_str_radix(bits, 16)
+                    .ok()
+                    .map(Self::from_bits_retain)
+            })
+    }
+}
+
+impl BitOr for CacheFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for CacheFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for CacheFlags {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl serde::Serialize for CacheFlags {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_u32(self.0);
+        }
+
+        let unknown = self.unknown_bits();
+        let unknown = (unknown != 0).then(|| format!("{unknown:#x}"));
+        serializer.collect_seq(self.names().map(str::to_string).chain(unknown))
+    }
+}
+
+/// Accepts a list of flag names in human-readable formats, as well as the raw bits that older
+/// versions of this crate serialized.
+impl<'de> serde::Deserialize<'de> for CacheFlags {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = CacheFlags;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a list of cache flag names or the raw bits")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, bits: u64) -> Result<CacheFlags, E> {
+                u32::try_from(bits)
+                    .map(CacheFlags::from_bits_retain)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(bits), &self))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<CacheFlags, A::Error> {
+                let mut flags = CacheFlags::empty();
+
+                while let Some(name) = seq.next_element::<String>()? {
+                    flags |= CacheFlags::from_name(&name).ok_or_else(|| {
+                        serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Str(&name),
+                            &"a cache flag name or hexadecimal bits",
+                        )
+                    })?;
+                }
+
+                Ok(flags)
+            }
+        }
+
+        // Binary formats like bincode and postcard can't tell a list from a number up front.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(Visitor)
+        } else {
+            deserializer.deserialize_u32(Visitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_tokens, Configure, Token};
+
+    use super::*;
+
+    #[test]
+    fn readable() {
+        let flags = CacheFlags::UNIFIED | CacheFlags::COMPLEX_INDEXING;
+        assert_tokens(
+            &flags.readable(),
+            &[
+                Token::Seq { len: None },
+                Token::Str("unified"),
+                Token::Str("complex_indexing"),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn readable_unknown_bits() {
+        let flags = CacheFlags::from_bits_retain(0x12);
+        assert_tokens(
+            &flags.readable(),
+            &[
+                Token::Seq { len: None },
+                Token::Str("inclusive"),
+                Token::Str("0x10"),
+                Token::SeqEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn compact() {
+        let flags = CacheFlags::from_bits_retain(0x13);
+        assert_tokens(&flags.compact(), &[Token::U32(0x13)]);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let flags = CacheFlags::from_bits_retain(0x15);
+        let json = serde_json::to_string(&flags).unwrap();
+        assert_eq!(json, r#"["unified","complex_indexing","0x10"]"#);
+        assert_eq!(serde_json::from_str::<CacheFlags>(&json).unwrap(), flags);
+
+        // Older versions serialized the raw bits.
+        assert_eq!(serde_json::from_str::<CacheFlags>("3").unwrap().bits(), 3);
+        assert!(serde_json::from_str::<CacheFlags>(r#"["shared"]"#).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use crate::{Core, CoreClass, Topology, UarchKind};
+
+/// Capacity of the fastest core of a system, which matches the scale of Linux' `cpu_capacity`.
+pub(crate) const MAX_CAPACITY: u32 = 1024;
+
+impl Topology {
+    /// Fills in [`Core::capacity`] for a topology that was just read from the system.
+    pub(crate) fn with_capacities(mut self) -> Self {
+        let capacities = self
+            .linux_capacities()
+            .unwrap_or_else(|| self.estimated_capacities());
+
+        self.cores = self
+            .cores
+            .iter()
+            .zip(capacities)
+            .map(|(core, capacity)| {
+                Arc::new(Core {
+                    capacity,
+                    ..Core::clone(core)
+                })
+            })
+            .collect();
+        self
+    }
+
+    /// `cpu_capacity` of every core, which Linux only reports on some heterogeneous systems.
+    fn linux_capacities(&self) -> Option<Vec<u32>> {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let capacities = self
+            .cores()
+            .iter()
+            .map(|core| {
+                let linux_id = self.core_processors(core)[0].linux_id?;
+                crate::sysfs::read(format!(
+                    "/sys/devices/system/cpu/cpu{linux_id}/cpu_capacity"
+                ))?
+                .parse()
+                .ok()
+            })
+            .collect();
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let capacities = None;
+
+        capacities
+    }
+
+    fn estimated_capacities(&self) -> Vec<u32> {
+        let cores = self.cores();
+
+        // Performance per clock only has a common scale when it is known for every core.
+        let ipc = cores
+            .iter()
+            .map(|core| relative_ipc(core.uarch.uarch))
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_else(|| cores.iter().map(|core| class_ipc(core.class)).collect());
+
+        let frequencies = cores
+            .iter()
+            .map(|core| match core.frequency_info.max {
+                0 => core.frequency,
+                max => max,
+            })
+            .collect::<Vec<_>>();
+        let frequency = |i: usize| {
+            if frequencies.contains(&0) {
+                1
+            } else {
+                frequencies[i]
+            }
+        };
+
+        let scores = (0..cores.len())
+            .map(|i| ipc[i] as u64 * frequency(i))
+            .collect::<Vec<_>>();
+        let max = scores.iter().copied().max().unwrap_or(0).max(1);
+
+        scores
+            .iter()
+            .map(|&score| ((score * MAX_CAPACITY as u64 + max / 2) / max).max(1) as u32)
+            .collect()
+    }
+}
+
+/// Approximate integer performance per clock of a microarchitecture, relative to Cortex-A55 at
+/// `100`.
+fn relative_ipc(uarch: UarchKind) -> Option<u32> {
+    use UarchKind as U;
+
+    Some(match uarch {
+        U::CortexA5 => 50,
+        U::CortexA7 | U::CortexA8 => 60,
+        U::CortexA32 | U::CortexA35 => 80,
+        U::CortexA9 | U::CortexA53 => 90,
+        U::CortexA55r0 | U::CortexA55 => 100,
+        U::CortexA12 | U::CortexA17 | U::CortexA65 | U::CortexA510 => 110,
+        U::CortexA520 => 120,
+        U::CortexA15 => 140,
+        U::CortexA57 => 160,
+        U::CortexA72 => 200,
+        U::CortexA73 => 210,
+        U::CortexA75 => 260,
+        U::CortexA76 | U::NeoverseN1 => 330,
+        U::CortexA77 => 380,
+        U::CortexA78 => 410,
+        U::CortexA710 | U::NeoverseN2 => 430,
+        U::CortexA715 => 460,
+        U::CortexX1 | U::NeoverseV1 => 480,
+        U::CortexA720 => 490,
+        U::CortexX2 => 520,
+        U::CortexX3 | U::NeoverseV2 => 590,
+        U::CortexX4 => 660,
+        U::Icestorm => 250,
+        U::Blizzard => 280,
+        U::Firestorm => 800,
+        U::Avalanche => 850,
+        _ => return None,
+    })
+}
+
+/// Rough performance per clock of each class of core, for microarchitectures that are not in
+/// [`relative_ipc()`].
+fn class_ipc(class: CoreClass) -> u32 {
+    match class {
+        CoreClass::Prime | CoreClass::Performance => 100,
+        CoreClass::Efficiency => 60,
+        CoreClass::LowPower => 40,
+    }
+}
+
+/// Capacity of cores in snapshots taken before it was recorded.
+pub(crate) fn default_capacity() -> u32 {
+    MAX_CAPACITY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::TestCore;
+
+    fn core(uarch: UarchKind, class: CoreClass, frequency: u64) -> TestCore {
+        TestCore {
+            class,
+            frequency,
+            ..TestCore::new(uarch)
+        }
+    }
+
+    #[test]
+    fn known_uarches() {
+        use CoreClass::*;
+
+        // Snapdragon 888.
+        let mut cores = vec![core(UarchKind::CortexX1, Prime, 2_840_000_000)];
+        cores.extend([core(UarchKind::CortexA78, Performance, 2_420_000_000); 3]);
+        cores.extend([core(UarchKind::CortexA55, Efficiency, 1_800_000_000); 4]);
+
+        let capacities = Topology::for_tests(&cores).estimated_capacities();
+        assert_eq!(capacities, [1024, 745, 745, 745, 135, 135, 135, 135]);
+    }
+
+    #[test]
+    fn unknown_uarch() {
+        use CoreClass::*;
+
+        // The classes are used for every core as soon as one of the uarches is unknown.
+        let cores = [
+            core(UarchKind::Unknown, Performance, 3_000_000_000),
+            core(UarchKind::CortexA55, Efficiency, 2_000_000_000),
+        ];
+
+        let capacities = Topology::for_tests(&cores).estimated_capacities();
+        assert_eq!(capacities, [1024, 410]);
+    }
+
+    #[test]
+    fn unknown_frequencies() {
+        use CoreClass::*;
+
+        let cores = [
+            core(UarchKind::CortexA78, Performance, 0),
+            core(UarchKind::CortexA55, Efficiency, 0),
+        ];
+
+        let capacities = Topology::for_tests(&cores).estimated_capacities();
+        assert_eq!(capacities, [1024, 250]);
+    }
+
+    #[test]
+    fn homogeneous() {
+        let cores = [core(UarchKind::Zen4, CoreClass::Performance, 0); 4];
+
+        let capacities = Topology::for_tests(&cores).estimated_capacities();
+        assert_eq!(capacities, [MAX_CAPACITY; 4]);
+    }
+
+    #[test]
+    fn ipc() {
+        assert_eq!(relative_ipc(UarchKind::CortexA55), Some(100));
+        assert_eq!(relative_ipc(UarchKind::Zen4), None);
+
+        // Every Cortex-X core is faster than the big core of its generation.
+        for (x, big) in [
+            (UarchKind::CortexX1, UarchKind::CortexA78),
+            (UarchKind::CortexX2, UarchKind::CortexA710),
+            (UarchKind::CortexX3, UarchKind::CortexA715),
+            (UarchKind::CortexX4, UarchKind::CortexA720),
+        ] {
+            assert!(relative_ipc(x) > relative_ipc(big), "{x:?}");
+        }
+
+        assert!(class_ipc(CoreClass::Performance) > class_ipc(CoreClass::Efficiency));
+        assert!(class_ipc(CoreClass::Efficiency) > class_ipc(CoreClass::LowPower));
+    }
+}
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use crate::cpuid::CoreCpuid;
+use crate::{Core, Topology, UarchClass, UarchFamily};
+
+/// Role of a core on heterogeneous systems, such as hybrid Intel processors and ARM big.LITTLE
+/// SoCs. Classes are ordered from the fastest to the slowest kind of core.
+///
+/// All cores of a homogeneous system are [`CoreClass::Performance`] cores.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum CoreClass {
+    /// The fastest few cores of a SoC with multiple tiers of performance cores, for example the
+    /// Cortex-X core next to Cortex-A7xx cores.
+    Prime,
+    #[default]
+    Performance,
+    /// Intel E-cores and ARM LITTLE cores.
+    Efficiency,
+    /// Efficiency cores outside of the main compute complex, such as the low power E-cores on the
+    /// SoC tile of Intel Meteor Lake, which don't share the L3 cache.
+    LowPower,
+}
+
+impl Topology {
+    /// Cores of the given `class`.
+    pub fn cores_by_class(&self, class: CoreClass) -> Vec<Arc<Core>> {
+        self.cores()
+            .iter()
+            .filter(|core| core.class == class)
+            .cloned()
+            .collect()
+    }
+
+    /// Fills in [`Core::class`] for a topology that was just read from the system.
+    pub(crate) fn with_core_classes(mut self, cpuids: &[CoreCpuid]) -> Self {
+        let classes = self.core_classes(cpuids);
+        self.cores = self
+            .cores
+            .iter()
+            .zip(classes)
+            .map(|(core, class)| {
+                Arc::new(Core {
+                    class,
+                    ..Core::clone(core)
+                })
+            })
+            .collect();
+        self
+    }
+
+    fn core_classes(&self, cpuids: &[CoreCpuid]) -> Vec<CoreClass> {
+        let max_frequency = self.cores().iter().map(|core| core.frequency).max();
+
+        let mut classes = self
+            .cores()
+            .iter()
+            .enumerate()
+            .map(|(i, core)| match cpuids[i].core_type {
+                // Intel Atom
+                Some(0x20) if self.core_processors(core)[0].cache.l3.is_none() => {
+                    CoreClass::LowPower
+                }
+                Some(0x20) => CoreClass::Efficiency,
+                // Intel Core
+                Some(0x40) => CoreClass::Performance,
+                _ => match core.uarch.class() {
+                    Some(UarchClass::Big | UarchClass::Server) => CoreClass::Performance,
+                    Some(UarchClass::Efficiency) => CoreClass::Efficiency,
+                    Some(UarchClass::LowPower) => CoreClass::LowPower,
+                    None if Some(core.frequency) < max_frequency => CoreClass::Efficiency,
+                    None => CoreClass::Performance,
+                },
+            })
+            .collect::<Vec<_>>();
+
+        // Homogeneous systems only have performance cores, whichever class their cores are.
+        if classes.windows(2).all(|pair| pair[0] == pair[1]) {
+            classes.fill(CoreClass::Performance);
+        }
+
+        // Promotes the Cortex-X cores, or otherwise the fastest performance cores, to prime cores
+        // when they are a minority of the performance cores.
+        let performance = (0..classes.len())
+            .filter(|&i| classes[i] == CoreClass::Performance)
+            .collect::<Vec<_>>();
+        let cortex_x = performance
+            .iter()
+            .copied()
+            .filter(|&i| self.cores[i].uarch.family() == Some(UarchFamily::CortexX))
+            .collect::<Vec<_>>();
+        let prime = if cortex_x.is_empty() {
+            let max = performance.iter().map(|&i| self.cores[i].frequency).max();
+            performance
+                .iter()
+                .copied()
+                .filter(|&i| Some(self.cores[i].frequency) == max)
+                .collect()
+        } else {
+            cortex_x
+        };
+
+        if prime.len() * 2 < performance.len() {
+            for i in prime {
+                classes[i] = CoreClass::Prime;
+            }
+        }
+
+        classes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::TestCore;
+    use crate::UarchKind;
+
+    fn classes(cores: &[TestCore], cpuids: &[CoreCpuid]) -> Vec<CoreClass> {
+        Topology::for_tests(cores).core_classes(cpuids)
+    }
+
+    #[test]
+    fn homogeneous() {
+        use CoreClass::*;
+
+        let cores = [TestCore::new(UarchKind::CortexA55); 4];
+        let cpuids = vec![CoreCpuid::default(); 4];
+        assert_eq!(classes(&cores, &cpuids), [Performance; 4]);
+
+        // Atom cores of an Atom processor.
+        let cores = [TestCore {
+            l3: Some(0),
+            ..TestCore::new(UarchKind::Goldmont)
+        }; 4];
+        let cpuids = vec![
+            CoreCpuid {
+                core_type: Some(0x20),
+                ..CoreCpuid::default()
+            };
+            4
+        ];
+        assert_eq!(classes(&cores, &cpuids), [Performance; 4]);
+    }
+
+    #[test]
+    fn hybrid() {
+        use CoreClass::*;
+
+        let mut cores = vec![TestCore::new(UarchKind::CortexA78); 4];
+        cores.extend([TestCore::new(UarchKind::CortexA55); 4]);
+        let cpuids = vec![CoreCpuid::default(); 8];
+        assert_eq!(
+            classes(&cores, &cpuids),
+            [
+                Performance,
+                Performance,
+                Performance,
+                Performance,
+                Efficiency,
+                Efficiency,
+                Efficiency,
+                Efficiency
+            ]
+        );
+
+        // Hybrid Intel processors, with the low power E-cores outside of the L3 cache.
+        let mut cores = vec![
+            TestCore {
+                l3: Some(0),
+                ..TestCore::new(UarchKind::SunnyCove)
+            };
+            4
+        ];
+        cores.extend(
+            [TestCore {
+                l3: Some(0),
+                ..TestCore::new(UarchKind::Goldmont)
+            }; 2],
+        );
+        cores.extend([TestCore::new(UarchKind::Goldmont); 2]);
+        let cpuids = [0x40, 0x40, 0x40, 0x40, 0x20, 0x20, 0x20, 0x20].map(|core_type| CoreCpuid {
+            core_type: Some(core_type),
+            ..CoreCpuid::default()
+        });
+        assert_eq!(
+            classes(&cores, &cpuids),
+            [
+                Performance,
+                Performance,
+                Performance,
+                Performance,
+                Efficiency,
+                Efficiency,
+                LowPower,
+                LowPower
+            ]
+        );
+    }
+
+    #[test]
+    fn promotes_prime_cores() {
+        use CoreClass::*;
+
+        let mut cores = vec![TestCore::new(UarchKind::CortexX1)];
+        cores.extend([TestCore::new(UarchKind::CortexA78); 3]);
+        cores.extend([TestCore::new(UarchKind::CortexA55); 4]);
+        let cpuids = vec![CoreCpuid::default(); 8];
+        assert_eq!(
+            classes(&cores, &cpuids),
+            [
+                Prime,
+                Performance,
+                Performance,
+                Performance,
+                Efficiency,
+                Efficiency,
+                Efficiency,
+                Efficiency
+            ]
+        );
+
+        // Without a Cortex-X core, the fastest performance cores are prime cores.
+        let mut cores = vec![TestCore {
+            frequency: 3_000_000_000,
+            ..TestCore::new(UarchKind::CortexA78)
+        }];
+        cores.extend(
+            [TestCore {
+                frequency: 2_400_000_000,
+                ..TestCore::new(UarchKind::CortexA78)
+            }; 3],
+        );
+        cores.extend(
+            [TestCore {
+                frequency: 1_800_000_000,
+                ..TestCore::new(UarchKind::CortexA55)
+            }; 4],
+        );
+        assert_eq!(
+            classes(&cores, &cpuids),
+            [
+                Prime,
+                Performance,
+                Performance,
+                Performance,
+                Efficiency,
+                Efficiency,
+                Efficiency,
+                Efficiency
+            ]
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt;
+
+use crate::Topology;
+
+/// Processor signature reported in the EAX register by CPUID leaf 1 (x86/x64-specific).
+///
+/// Serializes as the raw register value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct CpuidSignature(u32);
+
+impl CpuidSignature {
+    pub fn new(eax: u32) -> Self {
+        Self(eax)
+    }
+
+    /// Raw value of the EAX register.
+    pub fn raw(self) -> u32 {
+        self.0
+    }
+
+    pub fn stepping(self) -> u32 {
+        self.0 & 0xf
+    }
+
+    /// Model number without the extended model.
+    pub fn base_model(self) -> u32 {
+        (self.0 >> 4) & 0xf
+    }
+
+    /// Family number without the extended family.
+    pub fn base_family(self) -> u32 {
+        (self.0 >> 8) & 0xf
+    }
+
+    /// Processor type, `0` for original OEM processors.
+    pub fn processor_type(self) -> u32 {
+        (self.0 >> 12) & 0x3
+    }
+
+    pub fn extended_model(self) -> u32 {
+        (self.0 >> 16) & 0xf
+    }
+
+    pub fn extended_family(self) -> u32 {
+        (self.0 >> 20) & 0xff
+    }
+
+    /// Effective family, which includes the extended family for family `0xF` processors.
+    pub fn family(self) -> u32 {
+        match self.base_family() {
+            0xf => self.base_family() + self.extended_family(),
+            family => family,
+        }
+    }
+
+    /// Effective model, which includes the extended model for family `0x6` and `0xF` processors.
+    pub fn model(self) -> u32 {
+        match self.base_family() {
+            0x6 | 0xf => (self.extended_model() << 4) + self.base_model(),
+            _ => self.base_model(),
+        }
+    }
+}
+
+impl From<u32> for CpuidSignature {
+    fn from(eax: u32) -> Self {
+        Self::new(eax)
+    }
+}
+
+impl From<CpuidSignature> for u32 {
+    fn from(signature: CpuidSignature) -> Self {
+        signature.raw()
+    }
+}
+
+/// Formats the signature as `Family 25 Model 33 Stepping 0`.
+impl fmt::Display for CpuidSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Family {} Model {} Stepping {}",
+            self.family(),
+            self.model(),
+            self.stepping()
+        )
+    }
+}
+
+/// Leaves of CPUID that differ between the cores of a system, read in a single pass over the
+/// cores.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CoreCpuid {
+    /// Core type from CPUID leaf 0x1A on hybrid Intel processors.
+    pub(crate) core_type: Option<u8>,
+    /// Node ID from CPUID leaf 0x8000001E on AMD processors, or the die from the die level of
+    /// CPUID leaf 0x1F on Intel processors with multiple dies per package.
+    ///
+    /// The AMD node is a die on Zen 1, but covers a whole package, or a quadrant of it in NPS4
+    /// mode, on later processors. It never tells CCDs or CCXs apart, which are split by their L3
+    /// cache instead.
+    pub(crate) die_id: Option<u32>,
+    /// Base and maximum frequency in Hz from CPUID leaf 0x16.
+    pub(crate) frequencies: Option<(u64, u64)>,
+}
+
+impl Topology {
+    /// CPUID leaves of every core, which requires running on each of the cores when they can
+    /// differ.
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    #[allow(unused_unsafe)] // `__cpuid()` is only safe to call since Rust 1.87.
+    pub(crate) fn core_cpuids(&self) -> Vec<CoreCpuid> {
+        #[cfg(target_arch = "x86")]
+        use std::arch::x86::{__cpuid, __cpuid_count};
+        #[cfg(target_arch = "x86_64")]
+        use std::arch::x86_64::{__cpuid, __cpuid_count};
+
+        // `cpuid` of the host doesn't describe a mocked system.
+        if crate::sysfs::mocked() {
+            return vec![CoreCpuid::default(); self.cores().len()];
+        }
+
+        let max_leaf = unsafe { __cpuid(0) }.eax;
+        // CPUID.07H:EDX[15] indicates a hybrid processor.
+        let hybrid = max_leaf >= 7 && unsafe { __cpuid_count(7, 0) }.edx & (1 << 15) != 0;
+        // CPUID.80000001H:ECX[22] indicates the topology extensions.
+        let topology_extensions = unsafe { __cpuid(0x8000_0000) }.eax >= 0x8000_001e
+            && unsafe { __cpuid(0x8000_0001) }.ecx & (1 << 22) != 0;
+
+        // Every sub-leaf of leaf 0x1F describes a level of the topology from the bottom up, with
+        // the shift of the x2APIC ID to get to the next level.
+        let mut die_bits = None;
+        if !topology_extensions && max_leaf >= 0x1f {
+            let mut shift = 0;
+            for subleaf in 0..u8::MAX as u32 {
+                let leaf = unsafe { __cpuid_count(0x1f, subleaf) };
+                match (leaf.ecx >> 8) & 0xff {
+                    // Invalid level, which terminates the list.
+                    0 => break,
+                    // Die
+                    5 => {
+                        die_bits = Some((shift, leaf.eax & 0x1f));
+                        break;
+                    }
+                    _ => shift = leaf.eax & 0x1f,
+                }
+            }
+        }
+
+        let read = || CoreCpuid {
+            core_type: (hybrid && max_leaf >= 0x1a)
+                .then(|| (unsafe { __cpuid(0x1a) }.eax >> 24) as u8),
+            die_id: if topology_extensions {
+                // CPUID.8000001EH:ECX[7:0] is the node ID, see `CoreCpuid::die_id`.
+                Some(unsafe { __cpuid(0x8000_001e) }.ecx & 0xff)
+            } else {
+                die_bits.map(|(low, high)| {
+                    let x2apic_id = unsafe { __cpuid_count(0x1f, 0) }.edx as u64;
+                    ((x2apic_id & ((1 << high) - 1)) >> low) as u32
+                })
+            },
+            frequencies: (max_leaf >= 0x16).then(|| {
+                let leaf = unsafe { __cpuid(0x16) };
+                let mhz = |register: u32| (register & 0xffff) as u64 * 1_000_000;
+                (mhz(leaf.eax), mhz(leaf.ebx))
+            }),
+        };
+
+        if hybrid || topology_extensions || die_bits.is_some() {
+            if let Some(cpuids) = self.run_on_cores(read) {
+                return cpuids;
+            }
+        }
+
+        // The frequencies only differ between the cores of hybrid processors.
+        let cpuid = CoreCpuid {
+            core_type: None,
+            die_id: None,
+            ..read()
+        };
+        vec![cpuid; self.cores().len()]
+    }
+
+    /// CPUID leaves of every core, which are unknown on other architectures.
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+    pub(crate) fn core_cpuids(&self) -> Vec<CoreCpuid> {
+        vec![CoreCpuid::default(); self.cores().len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zen2() {
+        // AMD EPYC 7742.
+        let signature = CpuidSignature::new(0x00830f10);
+        assert_eq!(signature.base_family(), 0xf);
+        assert_eq!(signature.extended_family(), 0x8);
+        assert_eq!(signature.family(), 23);
+        assert_eq!(signature.model(), 49);
+        assert_eq!(signature.stepping(), 0);
+        assert_eq!(signature.to_string(), "Family 23 Model 49 Stepping 0");
+    }
+
+    #[test]
+    fn alder_lake() {
+        // Intel Core i9-12900K.
+        let signature = CpuidSignature::new(0x00090672);
+        assert_eq!(signature.family(), 6);
+        assert_eq!(signature.base_model(), 7);
+        assert_eq!(signature.extended_model(), 9);
+        assert_eq!(signature.model(), 151);
+        assert_eq!(signature.stepping(), 2);
+        assert_eq!(signature.to_string(), "Family 6 Model 151 Stepping 2");
+    }
+
+    #[test]
+    fn extended_model_is_ignored_before_family_6() {
+        let signature = CpuidSignature::new(0x00010543);
+        assert_eq!(signature.family(), 5);
+        assert_eq!(signature.model(), 4);
+        assert_eq!(signature.stepping(), 3);
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        assert_eq!(u32::from(CpuidSignature::from(0x00a20f12)), 0x00a20f12);
+    }
+}
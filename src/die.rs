@@ -0,0 +1,223 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::cpuid::CoreCpuid;
+use crate::{Package, Topology};
+
+/// Die, chiplet or core complex within a package, such as an AMD CCD or CCX or an Intel tile.
+///
+/// Cores are split into dies by the die IDs that CPUID and Linux report, by the cluster IDs that
+/// Linux reports on ARM, and by the L3 cache they share. The CCDs and CCXs of AMD processors are
+/// only told apart by their L3 cache, as AMD reports a node ID rather than a CCD ID. Threads that
+/// communicate a lot are best kept within a single die.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Die {
+    /// Index of the first logical processor on this die
+    pub processor_start: u32,
+    /// Number of logical processors on this die
+    pub processor_count: u32,
+    /// Index of the first core on this die
+    pub core_start: u32,
+    /// Number of cores on this die
+    pub core_count: u32,
+    /// Index of the die within its package
+    pub die_id: u32,
+    /// Physical package containing this die
+    pub package: Arc<Package>,
+}
+
+impl Topology {
+    /// Dies in the system, see [`Die`].
+    ///
+    /// This is empty when the cores of a die are not contiguous in the topology, in which case
+    /// [`Core::die`](crate::Core::die) is [`None`].
+    pub fn dies(&self) -> &[Arc<Die>] {
+        &self.dies
+    }
+
+    /// Fills in [`Topology::dies()`] for a topology that was just read from the system.
+    pub(crate) fn with_dies(mut self, cpuids: &[CoreCpuid]) -> Self {
+        self.dies = self.find_dies(cpuids, Path::new("/")).unwrap_or_default();
+        self
+    }
+
+    /// Splits the cores into dies, with the Linux topology read from a file system mounted at
+    /// `root`.
+    ///
+    /// Returns [`None`] when the cores of a die are not contiguous, such as when the firmware
+    /// numbers the cores of different dies alternately. [`Topology::dies()`] is empty then, rather
+    /// than listing dies that aren't ranges of cores.
+    fn find_dies(&self, cpuids: &[CoreCpuid], root: &Path) -> Option<Vec<Arc<Die>>> {
+        // Any of the sources that reports different values splits cores into separate dies.
+        let keys = self
+            .cores()
+            .iter()
+            .enumerate()
+            .map(|(i, core)| {
+                let processor = &self.core_processors(core)[0];
+                (
+                    core.package.core_start,
+                    processor
+                        .linux_id
+                        .and_then(|linux_id| sysfs_topology_id(root, linux_id, "die_id")),
+                    processor
+                        .linux_id
+                        .and_then(|linux_id| sysfs_cluster_id(root, linux_id)),
+                    cpuids[i].die_id,
+                    processor.cache.l3.as_ref().map(|l3| l3.processor_start),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut dies: Vec<Die> = vec![];
+        for (i, core) in self.cores().iter().enumerate() {
+            if i > 0 && keys[i] == keys[i - 1] {
+                let die = dies.last_mut()?;
+                die.core_count += 1;
+                die.processor_count += core.processor_count;
+                continue;
+            }
+
+            // Dies need to be contiguous ranges of cores.
+            if keys[..i].contains(&keys[i]) {
+                return None;
+            }
+
+            let die_id = dies
+                .iter()
+                .filter(|die| Arc::ptr_eq(&die.package, &core.package))
+                .count() as u32;
+            dies.push(Die {
+                processor_start: core.processor_start,
+                processor_count: core.processor_count,
+                core_start: i as u32,
+                core_count: 1,
+                die_id,
+                package: core.package.clone(),
+            });
+        }
+
+        Some(dies.into_iter().map(Arc::new).collect())
+    }
+}
+
+/// `die_id` or `cluster_id` that Linux reports for a logical processor, which is `-1` when
+/// unknown.
+#[cfg_attr(
+    not(any(target_os = "linux", target_os = "android")),
+    allow(unused_variables)
+)]
+fn sysfs_topology_id(root: &Path, linux_id: i32, name: &str) -> Option<i32> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let id = crate::sysfs::read(root.join(format!(
+        "sys/devices/system/cpu/cpu{linux_id}/topology/{name}"
+    )))
+    .and_then(|id| id.parse().ok())
+    .filter(|&id| id >= 0);
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    let id = None;
+
+    id
+}
+
+/// `cluster_id` that Linux reports for a logical processor on ARM, where it describes the cores
+/// that the firmware groups into a cluster. On x86 it is the ID of the L2 cache, which is below a
+/// die.
+fn sysfs_cluster_id(root: &Path, linux_id: i32) -> Option<i32> {
+    if cfg!(any(target_arch = "x86_64", target_arch = "x86")) {
+        return None;
+    }
+    sysfs_topology_id(root, linux_id, "cluster_id")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::TestCore;
+    use crate::UarchKind;
+
+    /// Root without any Linux topology.
+    const NO_SYSFS: &str = env!("CARGO_MANIFEST_DIR");
+
+    /// Core and die IDs of the dies found in `topology`.
+    fn dies(topology: &Topology, cpuids: &[CoreCpuid], root: &str) -> Option<Vec<(u32, u32)>> {
+        let dies = topology.find_dies(cpuids, Path::new(root))?;
+        Some(
+            dies.iter()
+                .map(|die| (die.core_start, die.die_id))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn packages() {
+        let cores = [0, 0, 1, 1].map(|package| TestCore {
+            package,
+            ..TestCore::new(UarchKind::Zen4)
+        });
+        let topology = Topology::for_tests(&cores);
+
+        // Die IDs are numbered within their package.
+        let cpuids = [CoreCpuid::default(); 4];
+        assert_eq!(
+            dies(&topology, &cpuids, NO_SYSFS),
+            Some(vec![(0, 0), (2, 0)])
+        );
+    }
+
+    #[test]
+    fn l3_caches() {
+        let cores = [0, 0, 1, 1].map(|l3| TestCore {
+            l3: Some(l3),
+            ..TestCore::new(UarchKind::Zen2)
+        });
+        let topology = Topology::for_tests(&cores);
+
+        let cpuids = [CoreCpuid::default(); 4];
+        assert_eq!(
+            dies(&topology, &cpuids, NO_SYSFS),
+            Some(vec![(0, 0), (2, 1)])
+        );
+    }
+
+    #[test]
+    fn cpuid_die_ids() {
+        let topology = Topology::for_tests(&[TestCore::new(UarchKind::Zen4); 4]);
+
+        let cpuids = [0, 0, 1, 1].map(|die_id| CoreCpuid {
+            die_id: Some(die_id),
+            ..CoreCpuid::default()
+        });
+        assert_eq!(
+            dies(&topology, &cpuids, NO_SYSFS),
+            Some(vec![(0, 0), (2, 1)])
+        );
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn sysfs_ids() {
+        let root = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/topology");
+        let topology = Topology::for_tests(&[TestCore::new(UarchKind::CortexA78); 8]);
+
+        // Processors 0-3 and 4-7 are on different dies, with two clusters each.
+        let cpuids = [CoreCpuid::default(); 8];
+        let expected = if cfg!(any(target_arch = "x86_64", target_arch = "x86")) {
+            vec![(0, 0), (4, 1)]
+        } else {
+            vec![(0, 0), (2, 1), (4, 2), (6, 3)]
+        };
+        assert_eq!(dies(&topology, &cpuids, root), Some(expected));
+    }
+
+    #[test]
+    fn non_contiguous() {
+        let topology = Topology::for_tests(&[TestCore::new(UarchKind::Zen4); 4]);
+
+        let cpuids = [0, 1, 0, 1].map(|die_id| CoreCpuid {
+            die_id: Some(die_id),
+            ..CoreCpuid::default()
+        });
+        assert_eq!(dies(&topology, &cpuids, NO_SYSFS), None);
+    }
+}
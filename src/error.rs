@@ -0,0 +1,135 @@
+use std::fmt;
+
+/// Errors that can occur while initializing [`crate::CpuInfo`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CpuInfoError {
+    /// `cpuinfo_initialize()` reported a failure, for example because `/proc` or `/sys` could not
+    /// be read.
+    InitializationFailed,
+    /// `cpuinfo` has no backend for the processor architecture of this platform.
+    UnsupportedPlatform,
+    /// Initialization succeeded, but at least one level of the topology is empty.
+    IncompleteTopology {
+        processors: u32,
+        cores: u32,
+        clusters: u32,
+        packages: u32,
+    },
+    /// A mock system was installed after `cpuinfo` had already been initialized.
+    AlreadyInitialized,
+    /// The ranges of the topology that was read from the system don't fit together.
+    InconsistentTopology(TopologyError),
+}
+
+impl fmt::Display for CpuInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InitializationFailed => f.write_str("cpuinfo failed to initialize"),
+            Self::UnsupportedPlatform => {
+                f.write_str("cpuinfo does not support the processor architecture of this platform")
+            }
+            Self::IncompleteTopology {
+                processors,
+                cores,
+                clusters,
+                packages,
+            } => write!(
+                f,
+                "cpuinfo reported an incomplete topology ({processors} processors, {cores} cores, {clusters} clusters, {packages} packages)"
+            ),
+            Self::AlreadyInitialized => f.write_str("cpuinfo has already been initialized"),
+            Self::InconsistentTopology(e) => write!(f, "failed to read the topology: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for CpuInfoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InconsistentTopology(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned when parsing a [`crate::VendorKind`] or [`crate::UarchKind`] from a name that is
+/// not known to this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseKindError {
+    pub(crate) name: String,
+}
+
+impl fmt::Display for ParseKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown name `{}`", self.name)
+    }
+}
+
+impl std::error::Error for ParseKindError {}
+
+/// Errors that can occur while loading a [`crate::SystemSnapshot`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// The snapshot is not valid JSON, does not match the layout of [`crate::SystemSnapshot`], or
+    /// contains an inconsistent [`TopologyError`].
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "failed to parse snapshot: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(e) => Some(e),
+        }
+    }
+}
+
+/// Inconsistencies in the `*_start` and `*_count` ranges of a [`crate::Topology`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TopologyError {
+    /// The range of `children` of `item` number `index` is empty or extends past the end of the
+    /// list of `children`.
+    InvalidRange {
+        item: &'static str,
+        index: usize,
+        children: &'static str,
+    },
+    /// The ranges of the topology don't assign `item` number `index` to any `parent`.
+    MissingParent {
+        item: &'static str,
+        index: usize,
+        parent: &'static str,
+    },
+}
+
+impl fmt::Display for TopologyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRange {
+                item,
+                index,
+                children,
+            } => write!(
+                f,
+                "topology has an invalid {children} range for {item} {index}"
+            ),
+            Self::MissingParent {
+                item,
+                index,
+                parent,
+            } => write!(f, "topology has no {parent} containing {item} {index}"),
+        }
+    }
+}
+
+impl std::error::Error for TopologyError {}
@@ -0,0 +1,640 @@
+//! Best-effort replacement for `cpuinfo` on targets that it can't be built for, or when the
+//! `fallback` feature is enabled.
+//!
+//! On Linux the topology and caches are read from `/sys/devices/system/cpu`. Elsewhere, every
+//! logical processor reported by [`std::thread::available_parallelism()`] is treated as a separate
+//! core without caches. Microarchitectures are not detected and always reported as
+//! [`UarchKind::Unknown`], and queries about the current thread are not supported.
+
+use std::sync::Arc;
+
+use crate::snapshot::parent;
+use crate::{
+    Cache, CacheInfo, CacheLevel, Cluster, Core, CoreClass, CpuInfo, CpuInfoError, CpuidSignature,
+    FrequencyInfo, IsaFeatures, Midr, Package, Processor, SystemSnapshot, Topology, TopologyError,
+    Uarch, UarchInfo, UarchKind, Vendor, VendorKind,
+};
+
+/// Logical processor as read from the system, before it is sorted into the topology.
+struct RawProcessor {
+    linux_id: Option<i32>,
+    package_id: u32,
+    cluster_id: u32,
+    core_id: u32,
+    frequency: u64,
+    midr: Option<Midr>,
+    caches: Vec<RawCache>,
+}
+
+struct RawCache {
+    level: CacheLevel,
+    cache: Cache,
+    /// Linux IDs of the logical processors that share this cache.
+    shared: Vec<i32>,
+}
+
+impl CpuInfo {
+    pub(crate) fn initialize() -> Result<Arc<SystemSnapshot>, CpuInfoError> {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let (processors, name) = (sysfs::processors(), sysfs::package_name());
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let (processors, name) = (None, String::new());
+
+        let processors = match processors {
+            Some(processors) if !processors.is_empty() => processors,
+            _ => Self::available_processors()?,
+        };
+
+        let topology =
+            Self::sort_topology(processors, name).map_err(CpuInfoError::InconsistentTopology)?;
+
+        Ok(Arc::new(SystemSnapshot {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            cpuinfo_version: "fallback".to_string(),
+            uarches: Self::group_uarches(&topology),
+            topology,
+            isa: Self::detect_isa(),
+        }))
+    }
+
+    pub(crate) fn current_processor_index() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn current_core_index() -> Option<usize> {
+        None
+    }
+
+    pub(crate) fn current_uarch_index() -> Option<usize> {
+        None
+    }
+
+    fn available_processors() -> Result<Vec<RawProcessor>, CpuInfoError> {
+        let count = std::thread::available_parallelism()
+            .map_err(|_| CpuInfoError::UnsupportedPlatform)?
+            .get();
+
+        Ok((0..count as u32)
+            .map(|i| RawProcessor {
+                linux_id: None,
+                package_id: 0,
+                cluster_id: 0,
+                core_id: i,
+                frequency: 0,
+                midr: None,
+                caches: vec![],
+            })
+            .collect())
+    }
+
+    /// Orders the processors by package, cluster and core like `cpuinfo` does, so that every
+    /// level of the topology covers a contiguous range of the level below it.
+    fn sort_topology(mut raw: Vec<RawProcessor>, name: String) -> Result<Topology, TopologyError> {
+        raw.sort_by_key(|p| (p.package_id, p.cluster_id, p.core_id, p.linux_id));
+
+        let (vendor, cpuid) = (Self::detect_vendor(), Self::detect_cpuid());
+
+        // Index of the package, cluster and core that every processor belongs to.
+        let processor_package = group_indices(raw.iter().map(|p| p.package_id));
+        let processor_cluster = group_indices(raw.iter().map(|p| (p.package_id, p.cluster_id)));
+        let processor_core =
+            group_indices(raw.iter().map(|p| (p.package_id, p.cluster_id, p.core_id)));
+
+        let package_processors = group_ranges(&processor_package);
+        let cluster_processors = group_ranges(&processor_cluster);
+        let core_processors = group_ranges(&processor_core);
+
+        let first = |ranges: &[(u32, u32)], groups: &[usize]| {
+            ranges
+                .iter()
+                .map(|(start, _)| groups[*start as usize])
+                .collect::<Vec<_>>()
+        };
+        let cluster_package = first(&cluster_processors, &processor_package);
+        let core_package = first(&core_processors, &processor_package);
+        let core_cluster = first(&core_processors, &processor_cluster);
+
+        let package_clusters = group_ranges(&cluster_package);
+        let package_cores = group_ranges(&core_package);
+        let cluster_cores = group_ranges(&core_cluster);
+
+        let packages = package_processors
+            .iter()
+            .enumerate()
+            .map(|(i, processors)| {
+                Arc::new(Package {
+                    name: name.clone(),
+                    processor_start: processors.0,
+                    processor_count: processors.1,
+                    core_start: package_cores[i].0,
+                    core_count: package_cores[i].1,
+                    cluster_start: package_clusters[i].0,
+                    cluster_count: package_clusters[i].1,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let clusters = cluster_processors
+            .iter()
+            .enumerate()
+            .map(|(i, processors)| {
+                let first = &raw[processors.0 as usize];
+                Arc::new(Cluster {
+                    processor_start: processors.0,
+                    processor_count: processors.1,
+                    core_start: cluster_cores[i].0,
+                    core_count: cluster_cores[i].1,
+                    cluster_id: first.cluster_id,
+                    package: packages[cluster_package[i]].clone(),
+                    vendor: vendor.clone(),
+                    uarch: Self::unknown_uarch(),
+                    cpuid,
+                    midr: first.midr,
+                    frequency: first.frequency,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let cores = core_processors
+            .iter()
+            .enumerate()
+            .map(|(i, processors)| {
+                let first = &raw[processors.0 as usize];
+                Arc::new(Core {
+                    processor_start: processors.0,
+                    processor_count: processors.1,
+                    core_id: first.core_id,
+                    cluster: clusters[core_cluster[i]].clone(),
+                    package: packages[core_package[i]].clone(),
+                    vendor: vendor.clone(),
+                    uarch: Self::unknown_uarch(),
+                    cpuid,
+                    midr: first.midr,
+                    frequency: first.frequency,
+                    class: CoreClass::default(),
+                    die: None,
+                    frequency_info: FrequencyInfo::default(),
+                    capacity: crate::capacity::MAX_CAPACITY,
+                    numa_node: None,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut topology = Topology {
+            packages,
+            clusters,
+            cores,
+            processors: vec![],
+            l1i: vec![],
+            l1d: vec![],
+            l2: vec![],
+            l3: vec![],
+            l4: vec![],
+            max_cache_size: 0,
+            dies: vec![],
+            numa_nodes: vec![],
+        };
+        Self::sort_caches(&raw, &mut topology);
+
+        let cache = |level, i| {
+            parent(topology.caches(level), i, |c| {
+                (c.processor_start, c.processor_count)
+            })
+        };
+        let processors = raw
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                let core = &topology.cores[processor_core[i]];
+                Processor {
+                    smt_id: i as u32 - core.processor_start,
+                    core: core.clone(),
+                    cluster: topology.clusters[processor_cluster[i]].clone(),
+                    package: topology.packages[processor_package[i]].clone(),
+                    linux_id: p.linux_id,
+                    windows_group_id: None,
+                    windows_processor_id: None,
+                    apic_id: None,
+                    cache: CacheInfo {
+                        l1i: cache(CacheLevel::L1i, i),
+                        l1d: cache(CacheLevel::L1d, i),
+                        l2: cache(CacheLevel::L2, i),
+                        l3: cache(CacheLevel::L3, i),
+                        l4: cache(CacheLevel::L4, i),
+                    },
+                    die: None,
+                    numa_node: None,
+                }
+            })
+            .collect();
+
+        topology.processors = processors;
+        topology.complete()
+    }
+
+    /// Deduplicates the caches of all processors, and assigns them the range of (sorted)
+    /// processors that share them.
+    ///
+    /// Caches whose processors are not contiguous after sorting can't be described by a range and
+    /// are left out, so that their processors have no cache at that level.
+    fn sort_caches(raw: &[RawProcessor], topology: &mut Topology) {
+        for processor in raw {
+            for raw_cache in &processor.caches {
+                let shared = raw
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| p.linux_id.is_some_and(|id| raw_cache.shared.contains(&id)))
+                    .map(|(i, _)| i as u32)
+                    .collect::<Vec<_>>();
+                let Some(&start) = shared.first() else {
+                    continue;
+                };
+                if shared.windows(2).any(|pair| pair[1] != pair[0] + 1) {
+                    continue;
+                }
+
+                let caches = match raw_cache.level {
+                    CacheLevel::L1i => &mut topology.l1i,
+                    CacheLevel::L1d => &mut topology.l1d,
+                    CacheLevel::L2 => &mut topology.l2,
+                    CacheLevel::L3 => &mut topology.l3,
+                    CacheLevel::L4 => &mut topology.l4,
+                };
+                if caches.iter().any(|cache| cache.processor_start == start) {
+                    continue;
+                }
+
+                topology.max_cache_size = topology.max_cache_size.max(raw_cache.cache.size);
+                caches.push(Arc::new(Cache {
+                    processor_start: start,
+                    processor_count: shared.len() as u32,
+                    ..raw_cache.cache.clone()
+                }));
+            }
+        }
+
+        for caches in [
+            &mut topology.l1i,
+            &mut topology.l1d,
+            &mut topology.l2,
+            &mut topology.l3,
+            &mut topology.l4,
+        ] {
+            caches.sort_by_key(|cache| cache.processor_start);
+        }
+    }
+
+    /// Groups cores with the same identification, as `cpuinfo` does for microarchitectures.
+    fn group_uarches(topology: &Topology) -> Vec<UarchInfo> {
+        let mut uarches: Vec<UarchInfo> = vec![];
+        for core in topology.cores() {
+            match uarches
+                .iter_mut()
+                .find(|uarch| uarch.cpuid == core.cpuid && uarch.midr == core.midr)
+            {
+                Some(uarch) => {
+                    uarch.core_count += 1;
+                    uarch.processor_count += core.processor_count;
+                }
+                None => uarches.push(UarchInfo {
+                    uarch: core.uarch.clone(),
+                    cpuid: core.cpuid,
+                    midr: core.midr,
+                    processor_count: core.processor_count,
+                    core_count: 1,
+                }),
+            }
+        }
+        uarches
+    }
+
+    fn unknown_uarch() -> Uarch {
+        Uarch {
+            uarch: UarchKind::Unknown,
+            name: UarchKind::Unknown.name().into(),
+        }
+    }
+
+    fn detect_vendor() -> Vendor {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        let vendor = {
+            #[cfg(target_arch = "x86")]
+            use std::arch::x86::__cpuid;
+            #[cfg(target_arch = "x86_64")]
+            use std::arch::x86_64::__cpuid;
+
+            // `__cpuid()` is only safe to call since Rust 1.87.
+            #[allow(unused_unsafe)]
+            let leaf = unsafe { __cpuid(0) };
+            let mut id = [0u8; 12];
+            id[..4].copy_from_slice(&leaf.ebx.to_le_bytes());
+            id[4..8].copy_from_slice(&leaf.edx.to_le_bytes());
+            id[8..].copy_from_slice(&leaf.ecx.to_le_bytes());
+
+            match &id {
+                b"GenuineIntel" => VendorKind::Intel,
+                b"AuthenticAMD" => VendorKind::Amd,
+                b"HygonGenuine" => VendorKind::Hygon,
+                b"CentaurHauls" => VendorKind::Via,
+                _ => VendorKind::Unknown,
+            }
+        };
+        #[cfg(all(
+            any(target_arch = "riscv64", target_arch = "riscv32"),
+            any(target_os = "linux", target_os = "android")
+        ))]
+        let vendor = VendorKind::from_mvendorid();
+        #[cfg(not(any(
+            target_arch = "x86_64",
+            target_arch = "x86",
+            all(
+                any(target_arch = "riscv64", target_arch = "riscv32"),
+                any(target_os = "linux", target_os = "android")
+            )
+        )))]
+        let vendor = VendorKind::Unknown;
+
+        Vendor {
+            vendor,
+            name: vendor.name().into(),
+        }
+    }
+
+    fn detect_cpuid() -> Option<CpuidSignature> {
+        #[cfg(target_arch = "x86")]
+        use std::arch::x86::__cpuid;
+        #[cfg(target_arch = "x86_64")]
+        use std::arch::x86_64::__cpuid;
+
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        #[allow(unused_unsafe)]
+        let cpuid = Some(CpuidSignature::new(unsafe { __cpuid(1) }.eax));
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+        let cpuid = None;
+
+        cpuid
+    }
+
+    fn detect_isa() -> IsaFeatures {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        let x86 = Some(crate::X86Isa::detect());
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
+        let x86 = None;
+
+        #[cfg(target_arch = "aarch64")]
+        let arm = Some(crate::ArmIsa::detect());
+        #[cfg(not(target_arch = "aarch64"))]
+        let arm = None;
+
+        #[cfg(all(
+            any(target_arch = "riscv64", target_arch = "riscv32"),
+            any(target_os = "linux", target_os = "android")
+        ))]
+        let riscv = crate::sysfs::proc_cpuinfo(&["isa"])
+            .and_then(|isa| crate::RiscvIsa::from_isa_string(&isa));
+        #[cfg(not(all(
+            any(target_arch = "riscv64", target_arch = "riscv32"),
+            any(target_os = "linux", target_os = "android")
+        )))]
+        let riscv = None;
+
+        IsaFeatures { x86, arm, riscv }
+    }
+}
+
+/// Numbers consecutive runs of equal keys, so that every item gets the index of its group.
+fn group_indices<K: PartialEq>(keys: impl Iterator<Item = K>) -> Vec<usize> {
+    let mut indices = vec![];
+    let mut last = None;
+    for key in keys {
+        let index = match (&last, indices.last()) {
+            (Some(last), Some(&index)) if *last == key => index,
+            (_, Some(&index)) => index + 1,
+            (_, None) => 0,
+        };
+        indices.push(index);
+        last = Some(key);
+    }
+    indices
+}
+
+/// Converts the group index of every item into the `(start, count)` range of every group.
+fn group_ranges(groups: &[usize]) -> Vec<(u32, u32)> {
+    let mut ranges: Vec<(u32, u32)> = vec![];
+    for (i, &group) in groups.iter().enumerate() {
+        if group == ranges.len() {
+            ranges.push((i as u32, 0));
+        }
+        ranges[group].1 += 1;
+    }
+    ranges
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod sysfs {
+    use super::{RawCache, RawProcessor};
+    use crate::sysfs::{parse_cpu_list, proc_cpuinfo, read};
+    use crate::{Cache, CacheFlags, CacheLevel, Midr};
+
+    fn read_u32(path: &str) -> Option<u32> {
+        read(path)?.parse().ok()
+    }
+
+    /// Parses cache sizes such as `32K`.
+    fn parse_size(size: &str) -> Option<u32> {
+        let (digits, multiplier) = match size.as_bytes().last()? {
+            b'K' => (&size[..size.len() - 1], 1024),
+            b'M' => (&size[..size.len() - 1], 1024 * 1024),
+            _ => (size, 1),
+        };
+        Some(digits.parse::<u32>().ok()? * multiplier)
+    }
+
+    pub(super) fn processors() -> Option<Vec<RawProcessor>> {
+        parse_cpu_list(&read("/sys/devices/system/cpu/online")?)?
+            .into_iter()
+            .map(processor)
+            .collect()
+    }
+
+    fn processor(linux_id: i32) -> Option<RawProcessor> {
+        let dir = format!("/sys/devices/system/cpu/cpu{linux_id}");
+
+        // cpuinfo puts all cores of an x86 package in a single cluster.
+        let cluster_id = if cfg!(any(target_arch = "x86_64", target_arch = "x86")) {
+            0
+        } else {
+            read_u32(&format!("{dir}/topology/cluster_id")).unwrap_or(0)
+        };
+
+        let frequency = read_u32(&format!("{dir}/cpufreq/base_frequency"))
+            .or_else(|| read_u32(&format!("{dir}/cpufreq/cpuinfo_max_freq")))
+            .map_or(0, |khz| khz as u64 * 1000);
+
+        let midr = read(format!("{dir}/regs/identification/midr_el1"))
+            .and_then(|midr| u64::from_str_radix(midr.trim_start_matches("0x"), 16).ok())
+            .map(|midr| Midr::new(midr as u32));
+
+        Some(RawProcessor {
+            linux_id: Some(linux_id),
+            package_id: read_u32(&format!("{dir}/topology/physical_package_id")).unwrap_or(0),
+            cluster_id,
+            core_id: read_u32(&format!("{dir}/topology/core_id")).unwrap_or(linux_id as u32),
+            frequency,
+            midr,
+            caches: (0..)
+                .map_while(|i| cache(&format!("{dir}/cache/index{i}")))
+                .collect(),
+        })
+    }
+
+    fn cache(dir: &str) -> Option<RawCache> {
+        let kind = read(format!("{dir}/type"))?;
+        let level = match (read_u32(&format!("{dir}/level"))?, kind.as_str()) {
+            (1, "Instruction") => CacheLevel::L1i,
+            (1, _) => CacheLevel::L1d,
+            (2, _) => CacheLevel::L2,
+            (3, _) => CacheLevel::L3,
+            (4, _) => CacheLevel::L4,
+            _ => return None,
+        };
+
+        let flags = if kind == "Unified" {
+            CacheFlags::UNIFIED
+        } else {
+            CacheFlags::empty()
+        };
+
+        Some(RawCache {
+            level,
+            cache: Cache {
+                size: read(format!("{dir}/size")).and_then(|size| parse_size(&size))?,
+                associativity: read_u32(&format!("{dir}/ways_of_associativity")).unwrap_or(0),
+                sets: read_u32(&format!("{dir}/number_of_sets")).unwrap_or(0),
+                partitions: read_u32(&format!("{dir}/physical_line_partition")).unwrap_or(1),
+                line_size: read_u32(&format!("{dir}/coherency_line_size")).unwrap_or(0),
+                flags,
+                processor_start: 0,
+                processor_count: 0,
+                numa_node: None,
+            },
+            shared: parse_cpu_list(&read(format!("{dir}/shared_cpu_list"))?)?,
+        })
+    }
+
+    /// Processor model name from `/proc/cpuinfo`, which is the same for every package.
+    pub(super) fn package_name() -> String {
+        proc_cpuinfo(&["model name", "Hardware", "cpu model"]).unwrap_or_default()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse_size;
+
+        #[test]
+        fn sizes() {
+            assert_eq!(parse_size("48K"), Some(48 * 1024));
+            assert_eq!(parse_size("32M"), Some(32 * 1024 * 1024));
+            assert_eq!(parse_size("512"), Some(512));
+            assert_eq!(parse_size("K"), None);
+            assert_eq!(parse_size(""), None);
+            assert_eq!(parse_size("4G"), None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CacheFlags;
+
+    #[test]
+    fn group_indices_numbers_runs() {
+        assert_eq!(
+            group_indices([3, 3, 1, 1, 1, 3].into_iter()),
+            [0, 0, 1, 1, 1, 2]
+        );
+        assert_eq!(group_indices(std::iter::empty::<u32>()), [] as [usize; 0]);
+    }
+
+    #[test]
+    fn group_ranges_of_runs() {
+        assert_eq!(group_ranges(&[0, 0, 1, 1, 1, 2]), [(0, 2), (2, 3), (5, 1)]);
+        assert_eq!(group_ranges(&[]), []);
+    }
+
+    fn raw_processor(linux_id: i32, core_id: u32, caches: Vec<RawCache>) -> RawProcessor {
+        RawProcessor {
+            linux_id: Some(linux_id),
+            package_id: 0,
+            cluster_id: 0,
+            core_id,
+            frequency: 0,
+            midr: None,
+            caches,
+        }
+    }
+
+    fn raw_cache(level: CacheLevel, shared: &[i32]) -> RawCache {
+        RawCache {
+            level,
+            cache: Cache {
+                size: 32 * 1024,
+                associativity: 8,
+                sets: 64,
+                partitions: 1,
+                line_size: 64,
+                flags: CacheFlags::empty(),
+                processor_start: 0,
+                processor_count: 0,
+                numa_node: None,
+            },
+            shared: shared.to_vec(),
+        }
+    }
+
+    #[test]
+    fn sort_caches_skips_interleaved_caches() {
+        // Linux numbers the SMT siblings of a core apart, so processors 0 and 2 form core 0 and
+        // the L1d caches are contiguous after sorting, while the L2 caches interleave.
+        let mut raw = vec![];
+        for linux_id in 0..4 {
+            let smt_siblings = [linux_id % 2, linux_id % 2 + 2];
+            let l2 = if linux_id < 2 { [0, 1] } else { [2, 3] };
+            raw.push(raw_processor(
+                linux_id,
+                linux_id as u32 % 2,
+                vec![
+                    raw_cache(CacheLevel::L1d, &smt_siblings),
+                    raw_cache(CacheLevel::L2, &l2),
+                ],
+            ));
+        }
+        raw.sort_by_key(|p| (p.core_id, p.linux_id));
+
+        let mut topology = Topology {
+            packages: vec![],
+            clusters: vec![],
+            cores: vec![],
+            processors: vec![],
+            l1i: vec![],
+            l1d: vec![],
+            l2: vec![],
+            l3: vec![],
+            l4: vec![],
+            max_cache_size: 0,
+            dies: vec![],
+            numa_nodes: vec![],
+        };
+        CpuInfo::sort_caches(&raw, &mut topology);
+
+        let ranges = topology
+            .l1d
+            .iter()
+            .map(|cache| (cache.processor_start, cache.processor_count))
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, [(0, 2), (2, 2)]);
+        assert!(topology.l2.is_empty());
+        assert_eq!(topology.max_cache_size, 32 * 1024);
+    }
+}
@@ -0,0 +1,143 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::cpuid::CoreCpuid;
+use crate::sysfs::read;
+use crate::{Cluster, Core, Topology};
+
+/// Clock frequencies of a core.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct FrequencyInfo {
+    /// Base (non-Turbo) frequency in Hz, or `0` when unknown
+    pub base: u64,
+    /// Maximum frequency with Turbo Boost or Precision Boost in Hz, or `0` when unknown
+    pub max: u64,
+    /// Minimum frequency in Hz, or `0` when unknown
+    pub min: u64,
+    /// Linux cpufreq scaling governor, such as `performance` or `schedutil`
+    pub governor: Option<String>,
+}
+
+impl FrequencyInfo {
+    /// Reads the cpufreq information of the logical processor with the given `linux_id` from a
+    /// file system mounted at `root`, which should contain `sys/devices/system/cpu`.
+    ///
+    /// Returns [`None`] when the processor has no `cpufreq` information.
+    pub fn from_root(root: impl AsRef<Path>, linux_id: i32) -> Option<Self> {
+        let dir = cpufreq_dir(root.as_ref(), linux_id);
+        let khz = |file| read_khz(&dir.join(file));
+
+        let info = Self {
+            // Only reported by the `intel_pstate` driver.
+            base: khz("base_frequency").unwrap_or(0),
+            max: khz("cpuinfo_max_freq").unwrap_or(0),
+            min: khz("cpuinfo_min_freq").unwrap_or(0),
+            governor: read(dir.join("scaling_governor")),
+        };
+        (info != Self::default()).then_some(info)
+    }
+
+    /// Samples the current frequency in Hz of the logical processor with the given `linux_id`
+    /// from a file system mounted at `root`, see [`FrequencyInfo::from_root()`].
+    pub fn current_from_root(root: impl AsRef<Path>, linux_id: i32) -> Option<u64> {
+        read_khz(&cpufreq_dir(root.as_ref(), linux_id).join("scaling_cur_freq"))
+    }
+}
+
+impl Topology {
+    /// Fills in [`Core::frequency_info`] for a topology that was just read from the system, and
+    /// [`Core::frequency`] and [`Cluster::frequency`] when they weren't reported.
+    pub(crate) fn with_frequencies(mut self, cpuids: &[CoreCpuid]) -> Self {
+        let frequencies = self
+            .cores()
+            .iter()
+            .zip(cpuids)
+            .map(|(core, cpuid)| {
+                let info = self.core_processors(core)[0]
+                    .linux_id
+                    .and_then(|linux_id| FrequencyInfo::from_root("/", linux_id))
+                    .unwrap_or_default();
+
+                // Fills in the base and maximum frequencies that cpufreq didn't report from CPUID
+                // leaf 0x16, which is only available on Intel processors.
+                match cpuid.frequencies {
+                    Some((base, max)) => FrequencyInfo {
+                        base: if info.base == 0 { base } else { info.base },
+                        max: if info.max == 0 { max } else { info.max },
+                        ..info
+                    },
+                    None => info,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.cores = self
+            .cores
+            .iter()
+            .zip(frequencies)
+            .map(|(core, frequency_info)| {
+                Arc::new(Core {
+                    frequency: if core.frequency == 0 {
+                        frequency_info.base
+                    } else {
+                        core.frequency
+                    },
+                    frequency_info,
+                    ..Core::clone(core)
+                })
+            })
+            .collect();
+
+        // Clusters have the base frequency of their cores, like the frequency reported by
+        // `cpuinfo`.
+        self.clusters = self
+            .clusters
+            .iter()
+            .map(|cluster| {
+                let core = &self.cores[cluster.core_start as usize];
+                Arc::new(Cluster {
+                    frequency: if cluster.frequency == 0 {
+                        core.frequency_info.base
+                    } else {
+                        cluster.frequency
+                    },
+                    ..Cluster::clone(cluster)
+                })
+            })
+            .collect();
+        self
+    }
+}
+
+fn cpufreq_dir(root: &Path, linux_id: i32) -> std::path::PathBuf {
+    root.join(format!("sys/devices/system/cpu/cpu{linux_id}/cpufreq"))
+}
+
+/// Reads a frequency in kHz, as used by cpufreq, and converts it to Hz.
+fn read_khz(path: &Path) -> Option<u64> {
+    Some(read(path)?.parse::<u64>().ok()? * 1000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::TestCore;
+    use crate::UarchKind;
+
+    #[test]
+    fn fills_in_unreported_frequencies() {
+        let topology = Topology::for_tests(&[TestCore::new(UarchKind::SunnyCove); 2]);
+        let cpuids = [CoreCpuid {
+            frequencies: Some((2_100_000_000, 4_700_000_000)),
+            ..CoreCpuid::default()
+        }; 2];
+        let topology = topology.with_frequencies(&cpuids).relink().unwrap();
+
+        // cpufreq of the host takes precedence over CPUID, so only the consistency is checked.
+        let core = &topology.cores()[0];
+        assert_ne!(core.frequency, 0);
+        assert_eq!(core.frequency, core.frequency_info.base);
+        assert_eq!(core.cluster.frequency, core.frequency);
+        assert_eq!(topology.clusters()[0].frequency, core.frequency);
+    }
+}
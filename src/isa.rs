@@ -0,0 +1,691 @@
+//! Instruction set extensions reported by `cpuinfo`.
+//!
+//! Only the sub-structure for the architecture the crate was compiled for is populated, the other
+//! ones are always [`None`].
+
+/// Instruction set extensions supported by the processors in the system.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IsaFeatures {
+    /// x86/x86-64 instruction set extensions.
+    pub x86: Option<X86Isa>,
+    /// ARM/AArch64 instruction set extensions.
+    pub arm: Option<ArmIsa>,
+    /// RISC-V instruction set extensions.
+    pub riscv: Option<RiscvIsa>,
+}
+
+/// Instruction set extensions of x86 and x86-64 processors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct X86Isa {
+    /// `RDTSCP` instruction
+    pub rdtscp: bool,
+    /// `RDPID` instruction
+    pub rdpid: bool,
+    /// `SYSENTER`/`SYSEXIT` instructions
+    pub sysenter: bool,
+    /// `RDMSR`/`WRMSR` instructions
+    pub msr: bool,
+    /// `CLZERO` instruction
+    pub clzero: bool,
+    /// `CLFLUSH` instruction
+    pub clflush: bool,
+    /// `CLFLUSHOPT` instruction
+    pub clflushopt: bool,
+    /// `MONITOR`/`MWAIT` instructions
+    pub mwait: bool,
+    /// `MONITORX`/`MWAITX` instructions
+    pub mwaitx: bool,
+    /// `FXSAVE`/`FXRSTOR` instructions
+    pub fxsave: bool,
+    /// `XSAVE`/`XRSTOR` instructions
+    pub xsave: bool,
+    /// 3dnow! instructions
+    pub three_d_now: bool,
+    /// 3dnow!+ instructions
+    pub three_d_now_plus: bool,
+    /// `PREFETCH` instruction
+    pub prefetch: bool,
+    /// `PREFETCHW` instruction
+    pub prefetchw: bool,
+    /// `PREFETCHWT1` instruction
+    pub prefetchwt1: bool,
+    /// SSE3 instructions
+    pub sse3: bool,
+    /// SSSE3 instructions
+    pub ssse3: bool,
+    /// SSE4.1 instructions
+    pub sse4_1: bool,
+    /// SSE4.2 instructions
+    pub sse4_2: bool,
+    /// SSE4a instructions
+    pub sse4a: bool,
+    /// Misaligned memory operands in SSE instructions
+    pub misaligned_sse: bool,
+    /// AVX instructions
+    pub avx: bool,
+    /// AVX-VNNI instructions
+    pub avxvnni: bool,
+    /// FMA3 instructions
+    pub fma3: bool,
+    /// FMA4 instructions
+    pub fma4: bool,
+    /// XOP instructions
+    pub xop: bool,
+    /// F16C instructions
+    pub f16c: bool,
+    /// AVX2 instructions
+    pub avx2: bool,
+    /// AVX-512 Foundation instructions
+    pub avx512f: bool,
+    /// AVX-512 Prefetch instructions
+    pub avx512pf: bool,
+    /// AVX-512 Exponential and Reciprocal instructions
+    pub avx512er: bool,
+    /// AVX-512 Conflict Detection instructions
+    pub avx512cd: bool,
+    /// AVX-512 Doubleword and Quadword instructions
+    pub avx512dq: bool,
+    /// AVX-512 Byte and Word instructions
+    pub avx512bw: bool,
+    /// AVX-512 Vector Length extensions
+    pub avx512vl: bool,
+    /// AVX-512 Integer Fused Multiply-Add instructions
+    pub avx512ifma: bool,
+    /// AVX-512 Vector Byte Manipulation instructions
+    pub avx512vbmi: bool,
+    /// AVX-512 Vector Byte Manipulation instructions 2
+    pub avx512vbmi2: bool,
+    /// AVX-512 Bit Algorithms instructions
+    pub avx512bitalg: bool,
+    /// AVX-512 Vector Population Count instructions
+    pub avx512vpopcntdq: bool,
+    /// AVX-512 Vector Neural Network instructions
+    pub avx512vnni: bool,
+    /// AVX-512 BFloat16 instructions
+    pub avx512bf16: bool,
+    /// AVX-512 FP16 instructions
+    pub avx512fp16: bool,
+    /// AVX-512 `VP2INTERSECT` instructions
+    pub avx512vp2intersect: bool,
+    /// AVX-512 4-iteration Vector Neural Network instructions
+    pub avx512_4vnniw: bool,
+    /// AVX-512 4-iteration Fused Multiply-Add instructions
+    pub avx512_4fmaps: bool,
+    /// AVX10.1 instructions
+    pub avx10_1: bool,
+    /// AVX10.2 instructions
+    pub avx10_2: bool,
+    /// AMX BFloat16 instructions
+    pub amx_bf16: bool,
+    /// AMX tile architecture
+    pub amx_tile: bool,
+    /// AMX 8-bit integer instructions
+    pub amx_int8: bool,
+    /// AMX FP16 instructions
+    pub amx_fp16: bool,
+    /// AVX-VNNI-INT8 instructions
+    pub avx_vnni_int8: bool,
+    /// AVX-VNNI-INT16 instructions
+    pub avx_vnni_int16: bool,
+    /// AVX-NE-CONVERT instructions
+    pub avx_ne_convert: bool,
+    /// Hardware Lock Elision
+    pub hle: bool,
+    /// Restricted Transactional Memory
+    pub rtm: bool,
+    /// `XTEST` instruction
+    pub xtest: bool,
+    /// Memory Protection Extensions
+    pub mpx: bool,
+    /// `CMPXCHG16B` instruction
+    pub cmpxchg16b: bool,
+    /// `CLWB` instruction
+    pub clwb: bool,
+    /// `MOVBE` instruction
+    pub movbe: bool,
+    /// `LAHF`/`SAHF` instructions in 64-bit mode
+    pub lahf_sahf: bool,
+    /// `RDFSBASE`/`RDGSBASE`/`WRFSBASE`/`WRGSBASE` instructions
+    pub fs_gs_base: bool,
+    /// `LZCNT` instruction
+    pub lzcnt: bool,
+    /// `POPCNT` instruction
+    pub popcnt: bool,
+    /// Trailing Bit Manipulation instructions
+    pub tbm: bool,
+    /// Bit Manipulation Instruction Set 1
+    pub bmi: bool,
+    /// Bit Manipulation Instruction Set 2
+    pub bmi2: bool,
+    /// `ADCX`/`ADOX` instructions
+    pub adx: bool,
+    /// AES instructions
+    pub aes: bool,
+    /// Vector AES instructions
+    pub vaes: bool,
+    /// `PCLMULQDQ` instruction
+    pub pclmulqdq: bool,
+    /// Vector `PCLMULQDQ` instruction
+    pub vpclmulqdq: bool,
+    /// Galois Field New Instructions
+    pub gfni: bool,
+    /// `RDRAND` instruction
+    pub rdrand: bool,
+    /// `RDSEED` instruction
+    pub rdseed: bool,
+    /// SHA instructions
+    pub sha: bool,
+    /// VIA PadLock Random Number Generator
+    pub rng: bool,
+    /// VIA PadLock Advanced Cryptography Engine
+    pub ace: bool,
+    /// VIA PadLock Advanced Cryptography Engine 2
+    pub ace2: bool,
+    /// VIA PadLock Hash Engine
+    pub phe: bool,
+    /// VIA PadLock Montgomery Multiplier
+    pub pmm: bool,
+    /// AMD Lightweight Profiling
+    pub lwp: bool,
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    not(cpuinfo_fallback)
+))]
+impl X86Isa {
+    pub(crate) fn from_raw(isa: &crate::cpuinfo_x86_isa) -> Self {
+        Self {
+            rdtscp: isa.rdtscp,
+            rdpid: isa.rdpid,
+            sysenter: isa.sysenter,
+            msr: isa.msr,
+            clzero: isa.clzero,
+            clflush: isa.clflush,
+            clflushopt: isa.clflushopt,
+            mwait: isa.mwait,
+            mwaitx: isa.mwaitx,
+            fxsave: isa.fxsave,
+            xsave: isa.xsave,
+            three_d_now: isa.three_d_now,
+            three_d_now_plus: isa.three_d_now_plus,
+            prefetch: isa.prefetch,
+            prefetchw: isa.prefetchw,
+            prefetchwt1: isa.prefetchwt1,
+            sse3: isa.sse3,
+            ssse3: isa.ssse3,
+            sse4_1: isa.sse4_1,
+            sse4_2: isa.sse4_2,
+            sse4a: isa.sse4a,
+            misaligned_sse: isa.misaligned_sse,
+            avx: isa.avx,
+            avxvnni: isa.avxvnni,
+            fma3: isa.fma3,
+            fma4: isa.fma4,
+            xop: isa.xop,
+            f16c: isa.f16c,
+            avx2: isa.avx2,
+            avx512f: isa.avx512f,
+            avx512pf: isa.avx512pf,
+            avx512er: isa.avx512er,
+            avx512cd: isa.avx512cd,
+            avx512dq: isa.avx512dq,
+            avx512bw: isa.avx512bw,
+            avx512vl: isa.avx512vl,
+            avx512ifma: isa.avx512ifma,
+            avx512vbmi: isa.avx512vbmi,
+            avx512vbmi2: isa.avx512vbmi2,
+            avx512bitalg: isa.avx512bitalg,
+            avx512vpopcntdq: isa.avx512vpopcntdq,
+            avx512vnni: isa.avx512vnni,
+            avx512bf16: isa.avx512bf16,
+            avx512fp16: isa.avx512fp16,
+            avx512vp2intersect: isa.avx512vp2intersect,
+            avx512_4vnniw: isa.avx512_4vnniw,
+            avx512_4fmaps: isa.avx512_4fmaps,
+            avx10_1: isa.avx10_1,
+            avx10_2: isa.avx10_2,
+            amx_bf16: isa.amx_bf16,
+            amx_tile: isa.amx_tile,
+            amx_int8: isa.amx_int8,
+            amx_fp16: isa.amx_fp16,
+            avx_vnni_int8: isa.avx_vnni_int8,
+            avx_vnni_int16: isa.avx_vnni_int16,
+            avx_ne_convert: isa.avx_ne_convert,
+            hle: isa.hle,
+            rtm: isa.rtm,
+            xtest: isa.xtest,
+            mpx: isa.mpx,
+            cmpxchg16b: isa.cmpxchg16b,
+            clwb: isa.clwb,
+            movbe: isa.movbe,
+            lahf_sahf: isa.lahf_sahf,
+            fs_gs_base: isa.fs_gs_base,
+            lzcnt: isa.lzcnt,
+            popcnt: isa.popcnt,
+            tbm: isa.tbm,
+            bmi: isa.bmi,
+            bmi2: isa.bmi2,
+            adx: isa.adx,
+            aes: isa.aes,
+            vaes: isa.vaes,
+            pclmulqdq: isa.pclmulqdq,
+            vpclmulqdq: isa.vpclmulqdq,
+            gfni: isa.gfni,
+            rdrand: isa.rdrand,
+            rdseed: isa.rdseed,
+            sha: isa.sha,
+            rng: isa.rng,
+            ace: isa.ace,
+            ace2: isa.ace2,
+            phe: isa.phe,
+            pmm: isa.pmm,
+            lwp: isa.lwp,
+        }
+    }
+}
+
+/// Instruction set extensions of ARM and AArch64 processors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ArmIsa {
+    /// Thumb instructions (AArch32 only)
+    #[serde(default)]
+    pub thumb: bool,
+    /// Thumb-2 instructions (AArch32 only)
+    #[serde(default)]
+    pub thumb2: bool,
+    /// ThumbEE execution environment (AArch32 only)
+    #[serde(default)]
+    pub thumbee: bool,
+    /// Jazelle Java bytecode execution (AArch32 only)
+    #[serde(default)]
+    pub jazelle: bool,
+    /// ARMv5TE DSP instructions (AArch32 only)
+    #[serde(default)]
+    pub armv5e: bool,
+    /// ARMv6 instructions (AArch32 only)
+    #[serde(default)]
+    pub armv6: bool,
+    /// ARMv6K instructions (AArch32 only)
+    #[serde(default)]
+    pub armv6k: bool,
+    /// ARMv7 instructions (AArch32 only)
+    #[serde(default)]
+    pub armv7: bool,
+    /// ARMv7 Multiprocessing Extensions (AArch32 only)
+    #[serde(default)]
+    pub armv7mp: bool,
+    /// ARMv8 AArch32 instructions (AArch32 only)
+    #[serde(default)]
+    pub armv8: bool,
+    /// `SDIV`/`UDIV` integer division instructions (AArch32 only)
+    #[serde(default)]
+    pub idiv: bool,
+    /// VFPv2 floating-point instructions (AArch32 only)
+    #[serde(default)]
+    pub vfpv2: bool,
+    /// VFPv3 floating-point instructions (AArch32 only)
+    #[serde(default)]
+    pub vfpv3: bool,
+    /// 32 double-precision VFP registers (AArch32 only)
+    #[serde(default)]
+    pub d32: bool,
+    /// Half-precision floating-point conversion instructions (AArch32 only)
+    #[serde(default)]
+    pub fp16: bool,
+    /// VFPv4 fused multiply-add instructions (AArch32 only)
+    #[serde(default)]
+    pub fma: bool,
+    /// Intel Wireless MMX instructions (AArch32 only)
+    #[serde(default)]
+    pub wmmx: bool,
+    /// Intel Wireless MMX 2 instructions (AArch32 only)
+    #[serde(default)]
+    pub wmmx2: bool,
+    /// Advanced SIMD (NEON) instructions (AArch32 only, always available on AArch64)
+    #[serde(default)]
+    pub neon: bool,
+    /// Large System Extensions (LSE) atomic instructions
+    pub atomics: bool,
+    /// BFloat16 instructions
+    pub bf16: bool,
+    /// Scalable Vector Extension
+    pub sve: bool,
+    /// Scalable Vector Extension 2
+    pub sve2: bool,
+    /// 8-bit integer matrix multiply instructions
+    pub i8mm: bool,
+    /// Scalable Matrix Extension
+    pub sme: bool,
+    /// Scalable Matrix Extension 2
+    pub sme2: bool,
+    /// Scalable Matrix Extension 2.1
+    pub sme2p1: bool,
+    /// SME 16-bit to 32-bit integer outer product instructions
+    pub sme_i16i32: bool,
+    /// SME 1-bit binary outer product instructions
+    pub sme_bi32i32: bool,
+    /// SME non-widening BFloat16 instructions
+    pub sme_b16b16: bool,
+    /// SME non-widening half-precision instructions
+    pub sme_f16f16: bool,
+    /// Maximum SVE vector length in bytes, or `0` when SVE is not supported
+    pub svelen: u32,
+    /// Rounding Double Multiply Accumulate instructions
+    pub rdm: bool,
+    /// Half-precision floating-point arithmetic instructions
+    pub fp16arith: bool,
+    /// 8-bit integer dot product instructions
+    pub dot: bool,
+    /// `FJCVTZS` JavaScript conversion instruction
+    pub jscvt: bool,
+    /// Complex number arithmetic instructions
+    pub fcma: bool,
+    /// `FMLAL`/`FMLSL` half-precision multiply-accumulate instructions
+    pub fhm: bool,
+    /// AES instructions
+    pub aes: bool,
+    /// SHA-1 instructions
+    pub sha1: bool,
+    /// SHA-2 instructions
+    pub sha2: bool,
+    /// `PMULL` polynomial multiply long instructions
+    pub pmull: bool,
+    /// CRC32 instructions
+    pub crc32: bool,
+}
+
+#[cfg(all(
+    any(target_arch = "aarch64", target_arch = "arm"),
+    not(cpuinfo_fallback)
+))]
+impl ArmIsa {
+    pub(crate) fn from_raw(isa: &crate::cpuinfo_arm_isa) -> Self {
+        Self {
+            #[cfg(target_arch = "arm")]
+            thumb: isa.thumb,
+            #[cfg(target_arch = "arm")]
+            thumb2: isa.thumb2,
+            #[cfg(target_arch = "arm")]
+            thumbee: isa.thumbee,
+            #[cfg(target_arch = "arm")]
+            jazelle: isa.jazelle,
+            #[cfg(target_arch = "arm")]
+            armv5e: isa.armv5e,
+            #[cfg(target_arch = "arm")]
+            armv6: isa.armv6,
+            #[cfg(target_arch = "arm")]
+            armv6k: isa.armv6k,
+            #[cfg(target_arch = "arm")]
+            armv7: isa.armv7,
+            #[cfg(target_arch = "arm")]
+            armv7mp: isa.armv7mp,
+            #[cfg(target_arch = "arm")]
+            armv8: isa.armv8,
+            #[cfg(target_arch = "arm")]
+            idiv: isa.idiv,
+            #[cfg(target_arch = "arm")]
+            vfpv2: isa.vfpv2,
+            #[cfg(target_arch = "arm")]
+            vfpv3: isa.vfpv3,
+            #[cfg(target_arch = "arm")]
+            d32: isa.d32,
+            #[cfg(target_arch = "arm")]
+            fp16: isa.fp16,
+            #[cfg(target_arch = "arm")]
+            fma: isa.fma,
+            #[cfg(target_arch = "arm")]
+            wmmx: isa.wmmx,
+            #[cfg(target_arch = "arm")]
+            wmmx2: isa.wmmx2,
+            #[cfg(target_arch = "arm")]
+            neon: isa.neon,
+            #[cfg(target_arch = "aarch64")]
+            neon: true,
+            #[cfg(target_arch = "aarch64")]
+            atomics: isa.atomics,
+            #[cfg(target_arch = "aarch64")]
+            bf16: isa.bf16,
+            #[cfg(target_arch = "aarch64")]
+            sve: isa.sve,
+            #[cfg(target_arch = "aarch64")]
+            sve2: isa.sve2,
+            #[cfg(target_arch = "aarch64")]
+            i8mm: isa.i8mm,
+            #[cfg(target_arch = "aarch64")]
+            sme: isa.sme,
+            #[cfg(target_arch = "aarch64")]
+            sme2: isa.sme2,
+            #[cfg(target_arch = "aarch64")]
+            sme2p1: isa.sme2p1,
+            #[cfg(target_arch = "aarch64")]
+            sme_i16i32: isa.sme_i16i32,
+            #[cfg(target_arch = "aarch64")]
+            sme_bi32i32: isa.sme_bi32i32,
+            #[cfg(target_arch = "aarch64")]
+            sme_b16b16: isa.sme_b16b16,
+            #[cfg(target_arch = "aarch64")]
+            sme_f16f16: isa.sme_f16f16,
+            #[cfg(target_arch = "aarch64")]
+            svelen: isa.svelen,
+            rdm: isa.rdm,
+            fp16arith: isa.fp16arith,
+            dot: isa.dot,
+            jscvt: isa.jscvt,
+            fcma: isa.fcma,
+            fhm: isa.fhm,
+            aes: isa.aes,
+            sha1: isa.sha1,
+            sha2: isa.sha2,
+            pmull: isa.pmull,
+            crc32: isa.crc32,
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(all(any(target_arch = "x86_64", target_arch = "x86"), cpuinfo_fallback))]
+impl X86Isa {
+    /// Extensions that the standard library can detect, used when `cpuinfo` is not available.
+    pub(crate) fn detect() -> Self {
+        use std::arch::is_x86_feature_detected as detected;
+
+        Self {
+            fxsave: detected!("fxsr"),
+            xsave: detected!("xsave"),
+            sse3: detected!("sse3"),
+            ssse3: detected!("ssse3"),
+            sse4_1: detected!("sse4.1"),
+            sse4_2: detected!("sse4.2"),
+            sse4a: detected!("sse4a"),
+            avx: detected!("avx"),
+            fma3: detected!("fma"),
+            f16c: detected!("f16c"),
+            avx2: detected!("avx2"),
+            avx512f: detected!("avx512f"),
+            avx512pf: detected!("avx512pf"),
+            avx512er: detected!("avx512er"),
+            avx512cd: detected!("avx512cd"),
+            avx512dq: detected!("avx512dq"),
+            avx512bw: detected!("avx512bw"),
+            avx512vl: detected!("avx512vl"),
+            avx512ifma: detected!("avx512ifma"),
+            avx512vbmi: detected!("avx512vbmi"),
+            avx512vbmi2: detected!("avx512vbmi2"),
+            avx512bitalg: detected!("avx512bitalg"),
+            avx512vpopcntdq: detected!("avx512vpopcntdq"),
+            avx512vnni: detected!("avx512vnni"),
+            rtm: detected!("rtm"),
+            cmpxchg16b: detected!("cmpxchg16b"),
+            movbe: detected!("movbe"),
+            lzcnt: detected!("lzcnt"),
+            popcnt: detected!("popcnt"),
+            tbm: detected!("tbm"),
+            bmi: detected!("bmi1"),
+            bmi2: detected!("bmi2"),
+            adx: detected!("adx"),
+            aes: detected!("aes"),
+            vaes: detected!("vaes"),
+            pclmulqdq: detected!("pclmulqdq"),
+            vpclmulqdq: detected!("vpclmulqdq"),
+            gfni: detected!("gfni"),
+            rdrand: detected!("rdrand"),
+            rdseed: detected!("rdseed"),
+            sha: detected!("sha"),
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", cpuinfo_fallback))]
+impl ArmIsa {
+    /// Extensions that the standard library can detect, used when `cpuinfo` is not available.
+    pub(crate) fn detect() -> Self {
+        use std::arch::is_aarch64_feature_detected as detected;
+
+        Self {
+            // Part of the base AArch64 architecture.
+            neon: true,
+            atomics: detected!("lse"),
+            bf16: detected!("bf16"),
+            sve: detected!("sve"),
+            sve2: detected!("sve2"),
+            i8mm: detected!("i8mm"),
+            rdm: detected!("rdm"),
+            fp16arith: detected!("fp16"),
+            dot: detected!("dotprod"),
+            jscvt: detected!("jsconv"),
+            fcma: detected!("fcma"),
+            fhm: detected!("fhm"),
+            aes: detected!("aes"),
+            sha1: detected!("sha2"),
+            sha2: detected!("sha2"),
+            pmull: detected!("aes"),
+            crc32: detected!("crc"),
+            ..Self::default()
+        }
+    }
+}
+
+/// Standard extensions of RISC-V processors, the single letter extensions of the ISA string.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RiscvIsa {
+    /// Base integer instruction set (`I`)
+    pub i: bool,
+    /// Reduced base integer instruction set with 16 registers (`E`)
+    pub e: bool,
+    /// Integer multiplication and division (`M`)
+    pub m: bool,
+    /// Atomic instructions (`A`)
+    pub a: bool,
+    /// Single-precision floating-point (`F`)
+    pub f: bool,
+    /// Double-precision floating-point (`D`)
+    pub d: bool,
+    /// Quad-precision floating-point (`Q`)
+    pub q: bool,
+    /// Compressed instructions (`C`)
+    pub c: bool,
+    /// Vector extension (`V`)
+    pub v: bool,
+}
+
+#[cfg(all(
+    any(target_arch = "riscv64", target_arch = "riscv32"),
+    not(cpuinfo_fallback)
+))]
+impl RiscvIsa {
+    pub(crate) fn from_raw(isa: &crate::cpuinfo_riscv_isa) -> Self {
+        Self {
+            i: isa.i,
+            e: isa.e,
+            m: isa.m,
+            a: isa.a,
+            f: isa.f,
+            d: isa.d,
+            q: isa.q,
+            c: isa.c,
+            v: isa.v,
+        }
+    }
+}
+
+#[cfg(any(
+    test,
+    all(
+        any(target_arch = "riscv64", target_arch = "riscv32"),
+        cpuinfo_fallback
+    )
+))]
+impl RiscvIsa {
+    /// Parses an ISA string such as `rv64imafdc_zicsr_zifencei` from `/proc/cpuinfo`, used when
+    /// `cpuinfo` is not available. Multi-letter extensions are ignored.
+    pub(crate) fn from_isa_string(isa: &str) -> Option<Self> {
+        let isa = isa.trim().to_ascii_lowercase();
+        let base = isa
+            .strip_prefix("rv64")
+            .or_else(|| isa.strip_prefix("rv32"))?
+            .split(['_', 'z', 's', 'x'])
+            .next()?;
+        // `G` is shorthand for `IMAFD`.
+        let has = |extension: char| {
+            base.contains(extension) || ("imafd".contains(extension) && base.contains('g'))
+        };
+
+        Some(Self {
+            i: has('i'),
+            e: has('e'),
+            m: has('m'),
+            a: has('a'),
+            f: has('f'),
+            d: has('d'),
+            q: has('q'),
+            c: has('c'),
+            v: has('v'),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn riscv_isa_string() {
+        assert_eq!(
+            RiscvIsa::from_isa_string("rv64imafdc_zicsr_zifencei"),
+            Some(RiscvIsa {
+                i: true,
+                m: true,
+                a: true,
+                f: true,
+                d: true,
+                c: true,
+                ..RiscvIsa::default()
+            })
+        );
+        // `G` expands to `IMAFD`.
+        assert_eq!(
+            RiscvIsa::from_isa_string("rv64gcv"),
+            Some(RiscvIsa {
+                i: true,
+                m: true,
+                a: true,
+                f: true,
+                d: true,
+                c: true,
+                v: true,
+                ..RiscvIsa::default()
+            })
+        );
+        assert_eq!(
+            RiscvIsa::from_isa_string("RV32EC\n"),
+            Some(RiscvIsa {
+                e: true,
+                c: true,
+                ..RiscvIsa::default()
+            })
+        );
+        assert_eq!(RiscvIsa::from_isa_string("x86_64"), None);
+    }
+}
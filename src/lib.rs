@@ -1,41 +1,122 @@
 #![doc = include_str!("../README.md")]
 
 use std::borrow::Cow;
-use std::sync::{Arc, Once};
-
-static INITIALIZED: Once = Once::new();
-
-#[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+use std::sync::{Arc, OnceLock};
+
+#[macro_use]
+mod macros;
+
+mod affinity;
+mod cache;
+mod capacity;
+mod class;
+mod cpuid;
+mod die;
+mod error;
+#[cfg(cpuinfo_fallback)]
+mod fallback;
+mod frequency;
+mod isa;
+mod limits;
+mod midr;
+#[cfg(all(
+    feature = "mock",
+    any(target_os = "linux", target_os = "android"),
+    not(cpuinfo_fallback)
+))]
+mod mock;
+mod numa;
+mod placement;
+mod snapshot;
+mod sysfs;
+mod topology;
+mod uarch;
+mod vendor;
+pub use affinity::CpuSet;
+pub use cache::CacheFlags;
+pub use class::CoreClass;
+pub use cpuid::CpuidSignature;
+pub use die::Die;
+pub use error::{CpuInfoError, ParseKindError, SnapshotError, TopologyError};
+pub use frequency::FrequencyInfo;
+pub use isa::{ArmIsa, IsaFeatures, RiscvIsa, X86Isa};
+pub use limits::CpuLimits;
+pub use midr::Midr;
+#[cfg(all(
+    feature = "mock",
+    any(target_os = "linux", target_os = "android"),
+    any(target_arch = "x86_64", target_arch = "x86"),
+    not(cpuinfo_fallback)
+))]
+pub use mock::MockCpuid;
+#[cfg(all(
+    feature = "mock",
+    any(target_os = "linux", target_os = "android"),
+    not(cpuinfo_fallback)
+))]
+pub use mock::MockSystem;
+pub use numa::NumaNode;
+pub use placement::PlacementPolicy;
+pub use snapshot::SystemSnapshot;
+pub use topology::Topology;
+pub use uarch::{UarchClass, UarchFamily, UarchKind};
+pub use vendor::VendorKind;
+
+/// Result of the one-time call to `cpuinfo_initialize()`, and everything that was read from it.
+static INITIALIZED: OnceLock<Result<Arc<SystemSnapshot>, CpuInfoError>> = OnceLock::new();
+
+#[cfg(all(target_os = "windows", target_arch = "x86_64", not(cpuinfo_fallback)))]
 mod bindings_x86_64_pc_windows_msvc;
-#[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+#[cfg(all(target_os = "windows", target_arch = "x86_64", not(cpuinfo_fallback)))]
 use bindings_x86_64_pc_windows_msvc::*;
 
-#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[cfg(all(target_os = "linux", target_arch = "x86_64", not(cpuinfo_fallback)))]
 mod bindings_x86_64_unknown_linux_gnu;
-#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[cfg(all(target_os = "linux", target_arch = "x86_64", not(cpuinfo_fallback)))]
 use bindings_x86_64_unknown_linux_gnu::*;
 
-#[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+#[cfg(all(target_os = "macos", target_arch = "x86_64", not(cpuinfo_fallback)))]
 mod bindings_x86_64_apple_darwin;
-#[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+#[cfg(all(target_os = "macos", target_arch = "x86_64", not(cpuinfo_fallback)))]
 use bindings_x86_64_apple_darwin::*;
 
-#[cfg(all(target_os = "windows", target_arch = "aarch64"))]
+#[cfg(all(target_os = "windows", target_arch = "aarch64", not(cpuinfo_fallback)))]
 mod bindings_aarch64_pc_windows_msvc;
-#[cfg(all(target_os = "windows", target_arch = "aarch64"))]
+#[cfg(all(target_os = "windows", target_arch = "aarch64", not(cpuinfo_fallback)))]
 use bindings_aarch64_pc_windows_msvc::*;
 
-#[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+#[cfg(all(target_os = "macos", target_arch = "aarch64", not(cpuinfo_fallback)))]
 mod bindings_aarch64_apple_darwin;
-#[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+#[cfg(all(target_os = "macos", target_arch = "aarch64", not(cpuinfo_fallback)))]
 use bindings_aarch64_apple_darwin::*;
 
-#[cfg(all(target_os = "android", target_arch = "aarch64"))]
+#[cfg(all(target_os = "android", target_arch = "aarch64", not(cpuinfo_fallback)))]
 mod bindings_aarch64_linux_android;
-#[cfg(all(target_os = "android", target_arch = "aarch64"))]
+#[cfg(all(target_os = "android", target_arch = "aarch64", not(cpuinfo_fallback)))]
 use bindings_aarch64_linux_android::*;
 
-pub struct CpuInfo;
+#[cfg(all(target_os = "linux", target_arch = "aarch64", not(cpuinfo_fallback)))]
+mod bindings_aarch64_unknown_linux_gnu;
+#[cfg(all(target_os = "linux", target_arch = "aarch64", not(cpuinfo_fallback)))]
+use bindings_aarch64_unknown_linux_gnu::*;
+
+#[cfg(all(target_os = "linux", target_arch = "arm", not(cpuinfo_fallback)))]
+mod bindings_armv7_unknown_linux_gnueabihf;
+#[cfg(all(target_os = "linux", target_arch = "arm", not(cpuinfo_fallback)))]
+use bindings_armv7_unknown_linux_gnueabihf::*;
+
+#[cfg(all(target_os = "linux", target_arch = "riscv64", not(cpuinfo_fallback)))]
+mod bindings_riscv64gc_unknown_linux_gnu;
+#[cfg(all(target_os = "linux", target_arch = "riscv64", not(cpuinfo_fallback)))]
+use bindings_riscv64gc_unknown_linux_gnu::*;
+
+#[derive(Debug, Clone)]
+pub struct CpuInfo {
+    system: Arc<SystemSnapshot>,
+    /// Whether `system` describes the machine this process runs on, rather than a snapshot that
+    /// was loaded with [`CpuInfo::from_snapshot()`].
+    live: bool,
+}
 
 impl Default for CpuInfo {
     fn default() -> Self {
@@ -44,56 +125,407 @@ impl Default for CpuInfo {
 }
 
 impl CpuInfo {
+    /// Initializes `cpuinfo`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if initialization fails, see [`CpuInfo::try_new()`] for a fallible alternative.
     pub fn new() -> Self {
-        INITIALIZED.call_once(|| {
-            unsafe { cpuinfo_initialize() };
-        });
+        match Self::try_new() {
+            Ok(info) => info,
+            Err(e) => panic!("Failed to initialize cpuinfo: {e}"),
+        }
+    }
+
+    /// Initializes `cpuinfo`, returning an error when it fails or when it reports an empty
+    /// topology.
+    ///
+    /// Initialization only happens once per process, subsequent calls return the same result.
+    pub fn try_new() -> Result<Self, CpuInfoError> {
+        let system = INITIALIZED.get_or_init(Self::initialize).clone()?;
+
+        Ok(Self { system, live: true })
+    }
+
+    /// Creates a [`CpuInfo`] that answers queries from `snapshot` instead of the current system.
+    ///
+    /// Queries about the current thread, such as [`CpuInfo::current_processor()`], always return
+    /// [`None`] for snapshots.
+    pub fn from_snapshot(snapshot: SystemSnapshot) -> Self {
+        Self {
+            system: Arc::new(snapshot),
+            live: false,
+        }
+    }
+
+    /// Captures everything that is known about the system, see [`SystemSnapshot::to_json()`] to
+    /// store it.
+    pub fn snapshot(&self) -> SystemSnapshot {
+        SystemSnapshot::clone(&self.system)
+    }
+
+    /// Returns `true` if this describes a snapshot rather than the system the process runs on.
+    pub fn is_snapshot(&self) -> bool {
+        !self.live
+    }
+
+    /// Deduplicated view of all packages, clusters, cores and logical processors in the system.
+    pub fn topology(&self) -> &Topology {
+        &self.system.topology
+    }
+
+    pub fn uarches(&self) -> Vec<UarchInfo> {
+        self.system.uarches.clone()
+    }
+
+    pub fn isa(&self) -> IsaFeatures {
+        self.system.isa.clone()
+    }
+
+    pub fn packages(&self) -> Vec<Arc<Package>> {
+        self.system.topology.packages().to_vec()
+    }
+
+    pub fn clusters(&self) -> Vec<Arc<Cluster>> {
+        self.system.topology.clusters().to_vec()
+    }
+
+    pub fn cores(&self) -> Vec<Arc<Core>> {
+        self.system.topology.cores().to_vec()
+    }
+
+    pub fn processors(&self) -> Vec<Processor> {
+        self.system.topology.processors().to_vec()
+    }
+
+    /// Logical processor that is executing the current thread.
+    ///
+    /// Returns [`None`] for snapshots and when the platform does not support this query. The thread
+    /// may be migrated at any time, so the result should only be treated as a hint.
+    pub fn current_processor(&self) -> Option<Processor> {
+        if !self.live {
+            return None;
+        }
+
+        let index = Self::current_processor_index()?;
+        self.system.topology.processors().get(index).cloned()
+    }
+
+    /// Core that is executing the current thread.
+    ///
+    /// Returns [`None`] for snapshots and when the platform does not support this query. The thread
+    /// may be migrated at any time, so the result should only be treated as a hint.
+    pub fn current_core(&self) -> Option<Arc<Core>> {
+        if !self.live {
+            return None;
+        }
+
+        let index = Self::current_core_index()?;
+        self.system.topology.cores().get(index).cloned()
+    }
+
+    /// Microarchitecture of the core that is executing the current thread.
+    ///
+    /// Returns [`None`] for snapshots and when the platform does not support this query. The thread
+    /// may be migrated at any time, so the result should only be treated as a hint.
+    pub fn current_uarch(&self) -> Option<UarchInfo> {
+        if !self.live {
+            return None;
+        }
+
+        let index = Self::current_uarch_index()?;
+        self.system.uarches.get(index).cloned()
+    }
+
+    /// Current frequency of the given logical processor in Hz, as last requested by the Linux
+    /// cpufreq driver.
+    ///
+    /// Returns [`None`] for snapshots, on other platforms and when cpufreq is not available, for
+    /// example in most virtual machines.
+    pub fn current_frequency(&self, processor: &Processor) -> Option<u64> {
+        if !self.live {
+            return None;
+        }
+
+        FrequencyInfo::current_from_root("/", processor.linux_id?)
+    }
+
+    /// Cores of the given `class`, see [`CoreClass`].
+    pub fn cores_by_class(&self, class: CoreClass) -> Vec<Arc<Core>> {
+        self.system.topology.cores_by_class(class)
+    }
+
+    /// Logical processors that a thread pool with the given `policy` should run on, see
+    /// [`Topology::placement()`].
+    pub fn placement(&self, policy: PlacementPolicy, reserved_cores: usize) -> Vec<Processor> {
+        self.system.topology.placement(policy, reserved_cores)
+    }
+
+    /// Logical processors that the current process may run on, restricted by its CPU affinity and
+    /// cgroups, see [`CpuLimits::current()`].
+    ///
+    /// The affinity is that of the calling thread, so a thread that was pinned to a single
+    /// processor only gets that processor. Snapshots are not restricted by the limits of the
+    /// current process and return every processor.
+    pub fn allowed_processors(&self) -> Vec<Processor> {
+        self.system.topology.allowed_processors(&self.limits())
+    }
+
+    /// Number of threads that the current process can keep busy, taking the CFS quota of
+    /// containers into account, see [`Topology::effective_parallelism()`].
+    ///
+    /// Like [`CpuInfo::allowed_processors()`], this uses the affinity of the calling thread and
+    /// returns `1` on a thread that was pinned to a single processor, so thread pools should be
+    /// sized before pinning their workers.
+    pub fn effective_parallelism(&self) -> usize {
+        self.system.topology.effective_parallelism(&self.limits())
+    }
+
+    fn limits(&self) -> CpuLimits {
+        // A mocked system doesn't run the calling thread or the cgroups of this process.
+        if self.live && !sysfs::mocked() {
+            CpuLimits::current()
+        } else {
+            CpuLimits::default()
+        }
+    }
+
+    /// Dies, chiplets or core complexes in the system, see [`Topology::dies()`].
+    pub fn dies(&self) -> Vec<Arc<Die>> {
+        self.system.topology.dies().to_vec()
+    }
+
+    /// NUMA nodes of the system, see [`Topology::numa_nodes()`].
+    pub fn numa_nodes(&self) -> Vec<Arc<NumaNode>> {
+        self.system.topology.numa_nodes().to_vec()
+    }
+
+    /// Every cache of the given `level` in the system, each shared cache instance is only returned
+    /// once.
+    ///
+    /// Use [`Topology::cache_processors()`] to find the logical processors sharing a cache.
+    pub fn caches(&self, level: CacheLevel) -> Vec<Arc<Cache>> {
+        self.system.topology.caches(level).to_vec()
+    }
+
+    /// Upper bound on the size of any cache in the system, in bytes.
+    pub fn max_cache_size(&self) -> u32 {
+        self.system.topology.max_cache_size()
+    }
+}
+
+#[cfg(not(cpuinfo_fallback))]
+impl CpuInfo {
+    fn current_processor_index() -> Option<usize> {
+        let processor = unsafe { cpuinfo_get_current_processor() };
+        if processor.is_null() {
+            return None;
+        }
+
+        Some(unsafe { index_of(cpuinfo_get_processors(), processor) })
+    }
+
+    fn current_core_index() -> Option<usize> {
+        let core = unsafe { cpuinfo_get_current_core() };
+        if core.is_null() {
+            return None;
+        }
+
+        Some(unsafe { index_of(cpuinfo_get_cores(), core) })
+    }
+
+    fn current_uarch_index() -> Option<usize> {
+        let index = unsafe { cpuinfo_get_current_uarch_index_with_default(u32::MAX) };
+        (index != u32::MAX).then_some(index as usize)
+    }
+
+    fn initialize() -> Result<Arc<SystemSnapshot>, CpuInfoError> {
+        #[cfg(all(feature = "mock", any(target_os = "linux", target_os = "android")))]
+        mock::seal();
+
+        if !unsafe { cpuinfo_initialize() } {
+            #[cfg(any(
+                target_arch = "x86_64",
+                target_arch = "x86",
+                target_arch = "aarch64",
+                target_arch = "arm",
+                target_arch = "riscv64",
+                target_arch = "riscv32"
+            ))]
+            let error = CpuInfoError::InitializationFailed;
+            #[cfg(not(any(
+                target_arch = "x86_64",
+                target_arch = "x86",
+                target_arch = "aarch64",
+                target_arch = "arm",
+                target_arch = "riscv64",
+                target_arch = "riscv32"
+            )))]
+            let error = CpuInfoError::UnsupportedPlatform;
+
+            return Err(error);
+        }
+
+        let (processors, cores, clusters, packages) = unsafe {
+            (
+                cpuinfo_get_processors_count(),
+                cpuinfo_get_cores_count(),
+                cpuinfo_get_clusters_count(),
+                cpuinfo_get_packages_count(),
+            )
+        };
+
+        if processors == 0 || cores == 0 || clusters == 0 || packages == 0 {
+            return Err(CpuInfoError::IncompleteTopology {
+                processors,
+                cores,
+                clusters,
+                packages,
+            });
+        }
 
-        Self {}
+        Ok(Arc::new(SystemSnapshot {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            cpuinfo_version: env!("CPUINFO_REVISION").to_string(),
+            topology: Self::build_topology().map_err(CpuInfoError::InconsistentTopology)?,
+            uarches: Self::read_uarches(),
+            isa: Self::read_isa(),
+        }))
     }
 
+    /// Reads the whole topology from `cpuinfo`, sharing a single [`Arc`] per package, cluster and
+    /// core between everything that references it.
+    fn build_topology() -> Result<Topology, TopologyError> {
+        let packages = (0..unsafe { cpuinfo_get_packages_count() })
+            .map(|i| Self::package(unsafe { cpuinfo_get_package(i) }))
+            .collect::<Vec<_>>();
+
+        let clusters = (0..unsafe { cpuinfo_get_clusters_count() })
+            .map(|i| {
+                let cluster = unsafe { cpuinfo_get_cluster(i) };
+                let package = unsafe { index_of(cpuinfo_get_packages(), (*cluster).package) };
+
+                Self::cluster(cluster, packages[package].clone())
+            })
+            .collect::<Vec<_>>();
+
+        let cores = (0..unsafe { cpuinfo_get_cores_count() })
+            .map(|i| {
+                let core = unsafe { cpuinfo_get_core(i) };
+                let cluster = unsafe { index_of(cpuinfo_get_clusters(), (*core).cluster) };
+                let package = unsafe { index_of(cpuinfo_get_packages(), (*core).package) };
+
+                Self::core(core, clusters[cluster].clone(), packages[package].clone())
+            })
+            .collect::<Vec<_>>();
+
+        let mut topology = Topology {
+            packages,
+            clusters,
+            cores,
+            processors: vec![],
+            l1i: Self::cache_level(cpuinfo_get_l1i_caches_count, cpuinfo_get_l1i_cache),
+            l1d: Self::cache_level(cpuinfo_get_l1d_caches_count, cpuinfo_get_l1d_cache),
+            l2: Self::cache_level(cpuinfo_get_l2_caches_count, cpuinfo_get_l2_cache),
+            l3: Self::cache_level(cpuinfo_get_l3_caches_count, cpuinfo_get_l3_cache),
+            l4: Self::cache_level(cpuinfo_get_l4_caches_count, cpuinfo_get_l4_cache),
+            max_cache_size: unsafe { cpuinfo_get_max_cache_size() },
+            dies: vec![],
+            numa_nodes: vec![],
+        };
+
+        topology.processors = (0..unsafe { cpuinfo_get_processors_count() })
+            .map(|i| {
+                let processor = unsafe { cpuinfo_get_processor(i) };
+                let core = unsafe { index_of(cpuinfo_get_cores(), (*processor).core) };
+                let cluster = unsafe { index_of(cpuinfo_get_clusters(), (*processor).cluster) };
+                let package = unsafe { index_of(cpuinfo_get_packages(), (*processor).package) };
+
+                Self::processor(
+                    processor,
+                    topology.cores[core].clone(),
+                    topology.clusters[cluster].clone(),
+                    topology.packages[package].clone(),
+                    Self::cache_info(unsafe { &(*processor).cache }, &topology),
+                )
+            })
+            .collect();
+
+        topology.complete()
+    }
+
+    #[allow(clippy::unnecessary_cast)] // `cpuinfo_uarch` is a `c_int` on Windows.
     fn uarch(uarch: cpuinfo_uarch) -> Uarch {
+        let uarch = uarch as u32;
         Uarch {
-            uarch,
+            uarch: UarchKind::from(uarch),
             name: uarch_to_string(uarch).into(),
         }
     }
 
-    pub fn uarches(&self) -> Vec<UarchInfo> {
+    fn uarch_info(uarch_info: *const cpuinfo_uarch_info) -> UarchInfo {
+        unsafe {
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            let cpuid = Some(CpuidSignature::new((*uarch_info).cpuid));
+            #[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
+            let cpuid = None;
+
+            #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+            let midr = Some(Midr::new((*uarch_info).midr));
+            #[cfg(all(not(target_arch = "aarch64"), not(target_arch = "arm")))]
+            let midr = None;
+
+            UarchInfo {
+                uarch: Self::uarch((*uarch_info).uarch),
+                cpuid,
+                midr,
+                processor_count: (*uarch_info).processor_count,
+                core_count: (*uarch_info).core_count,
+            }
+        }
+    }
+
+    fn read_uarches() -> Vec<UarchInfo> {
         let count = unsafe { cpuinfo_get_uarchs_count() };
         let mut infos = vec![];
 
         for i in 0..count {
-            let uarch_info = unsafe { cpuinfo_get_uarch(i) };
-            infos.push(unsafe {
-                #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-                let cpuid = Some((*uarch_info).cpuid);
-                #[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
-                let cpuid = None;
-
-                #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
-                let midr = Some((*uarch_info).midr);
-                #[cfg(all(not(target_arch = "aarch64"), not(target_arch = "arm")))]
-                let midr = None;
-
-                UarchInfo {
-                    uarch: Self::uarch((*uarch_info).uarch),
-                    cpuid,
-                    midr,
-                    processor_count: (*uarch_info).processor_count,
-                    core_count: (*uarch_info).core_count,
-                }
-            })
+            infos.push(Self::uarch_info(unsafe { cpuinfo_get_uarch(i) }))
         }
 
         infos
     }
 
+    fn read_isa() -> IsaFeatures {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        let x86 = Some(X86Isa::from_raw(unsafe {
+            &*std::ptr::addr_of!(cpuinfo_isa)
+        }));
+        #[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
+        let x86 = None;
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+        let arm = Some(ArmIsa::from_raw(unsafe {
+            &*std::ptr::addr_of!(cpuinfo_isa)
+        }));
+        #[cfg(all(not(target_arch = "aarch64"), not(target_arch = "arm")))]
+        let arm = None;
+
+        #[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
+        let riscv = Some(RiscvIsa::from_raw(unsafe {
+            &*std::ptr::addr_of!(cpuinfo_isa)
+        }));
+        #[cfg(all(not(target_arch = "riscv64"), not(target_arch = "riscv32")))]
+        let riscv = None;
+
+        IsaFeatures { x86, arm, riscv }
+    }
+
     fn package(package: *const cpuinfo_package) -> Arc<Package> {
         Arc::new(unsafe {
             Package {
                 name: std::ffi::CStr::from_bytes_until_nul(bytemuck::cast_slice(
-                    &(*package).name[..],
+                    &(&(*package).name)[..],
                 ))
                 .unwrap()
                 .to_str()
@@ -111,12 +543,12 @@ impl CpuInfo {
 
     fn cluster(cluster: *const cpuinfo_cluster, package: Arc<Package>) -> Arc<Cluster> {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-        let cpuid = Some(unsafe { (*cluster).cpuid });
+        let cpuid = Some(CpuidSignature::new(unsafe { (*cluster).cpuid }));
         #[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
         let cpuid = None;
 
         #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
-        let midr = Some(unsafe { (*cluster).midr });
+        let midr = Some(Midr::new(unsafe { (*cluster).midr }));
         #[cfg(all(not(target_arch = "aarch64"), not(target_arch = "arm")))]
         let midr = None;
 
@@ -137,21 +569,34 @@ impl CpuInfo {
         })
     }
 
+    #[allow(clippy::unnecessary_cast)] // `cpuinfo_vendor` is a `c_int` on Windows.
     fn vendor(vendor: cpuinfo_vendor) -> Vendor {
+        let vendor = vendor as u32;
+        #[cfg(all(
+            any(target_arch = "riscv64", target_arch = "riscv32"),
+            any(target_os = "linux", target_os = "android")
+        ))]
+        if VendorKind::from(vendor) == VendorKind::Unknown {
+            let vendor = VendorKind::from_mvendorid();
+            return Vendor {
+                vendor,
+                name: vendor.name().into(),
+            };
+        }
         Vendor {
-            vendor,
+            vendor: VendorKind::from(vendor),
             name: vendor_to_string(vendor).into(),
         }
     }
 
     fn core(core: *const cpuinfo_core, cluster: Arc<Cluster>, package: Arc<Package>) -> Arc<Core> {
         #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-        let cpuid = Some(unsafe { (*core).cpuid });
+        let cpuid = Some(CpuidSignature::new(unsafe { (*core).cpuid }));
         #[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
         let cpuid = None;
 
         #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
-        let midr = Some(unsafe { (*core).midr });
+        let midr = Some(Midr::new(unsafe { (*core).midr }));
         #[cfg(all(not(target_arch = "aarch64"), not(target_arch = "arm")))]
         let midr = None;
 
@@ -167,107 +612,117 @@ impl CpuInfo {
                 cpuid,
                 midr,
                 frequency: (*core).frequency,
+                class: CoreClass::default(),
+                die: None,
+                frequency_info: FrequencyInfo::default(),
+                capacity: capacity::MAX_CAPACITY,
+                numa_node: None,
             }
         })
     }
 
-    pub fn cores(&self) -> Vec<Arc<Core>> {
-        let count = unsafe { cpuinfo_get_cores_count() };
-        let mut cores = vec![];
-
-        for i in 0..count {
-            let core = unsafe { cpuinfo_get_core(i) };
-            let package = Self::package(unsafe { (*core).package });
-            let cluster = Self::cluster(unsafe { (*core).cluster }, package.clone());
-
-            cores.push(Self::core(core, cluster.clone(), package.clone()))
-        }
-
-        cores
-    }
-
-    fn cache(cache: *const cpuinfo_cache) -> Option<Cache> {
-        if cache.is_null() {
-            return None;
-        }
-
-        Some(unsafe {
+    fn cache(cache: *const cpuinfo_cache) -> Arc<Cache> {
+        Arc::new(unsafe {
             Cache {
                 size: (*cache).size,
                 associativity: (*cache).associativity,
                 sets: (*cache).sets,
                 partitions: (*cache).partitions,
                 line_size: (*cache).line_size,
-                flags: (*cache).flags,
+                flags: CacheFlags::from_bits_retain((*cache).flags),
                 processor_start: (*cache).processor_start,
                 processor_count: (*cache).processor_count,
+                numa_node: None,
             }
         })
     }
 
-    fn cache_info(cache_info: &cpuinfo_processor__bindgen_ty_1) -> CacheInfo {
-        CacheInfo {
-            l1i: Self::cache(cache_info.l1i),
-            l1d: Self::cache(cache_info.l1d),
-            l2: Self::cache(cache_info.l2),
-            l3: Self::cache(cache_info.l3),
-            l4: Self::cache(cache_info.l4),
-        }
+    fn cache_level(
+        count: unsafe extern "C" fn() -> u32,
+        cache: unsafe extern "C" fn(u32) -> *const cpuinfo_cache,
+    ) -> Vec<Arc<Cache>> {
+        (0..unsafe { count() })
+            .map(|i| Self::cache(unsafe { cache(i) }))
+            .collect()
     }
 
-    pub fn processors(&self) -> Vec<Processor> {
-        let count = unsafe { cpuinfo_get_processors_count() };
-        let mut processors = vec![];
+    /// Looks up the shared [`Cache`] that `cache` points to in the `cpuinfo` array starting at
+    /// `first`.
+    fn shared_cache(
+        cache: *const cpuinfo_cache,
+        first: *const cpuinfo_cache,
+        caches: &[Arc<Cache>],
+    ) -> Option<Arc<Cache>> {
+        if cache.is_null() {
+            return None;
+        }
 
-        for i in 0..count {
-            let processor = unsafe { cpuinfo_get_processor(i) };
-            let package = Self::package(unsafe { (*processor).package });
-            let cluster = Self::cluster(unsafe { (*processor).cluster }, package.clone());
-            let core = Self::core(
-                unsafe { (*processor).core },
-                cluster.clone(),
-                package.clone(),
-            );
-
-            #[cfg(target_os = "linux")]
-            let linux_id = Some(unsafe { (*processor).linux_id });
-            #[cfg(not(target_os = "linux"))]
-            let linux_id = None;
-
-            #[cfg(target_os = "windows")]
-            let (windows_group_id, windows_processor_id) = {
-                (
-                    Some(unsafe { (*processor).windows_group_id }),
-                    Some(unsafe { (*processor).windows_processor_id }),
-                )
-            };
-            #[cfg(not(target_os = "windows"))]
-            let (windows_group_id, windows_processor_id) = (None, None);
+        Some(caches[unsafe { index_of(first, cache) }].clone())
+    }
 
-            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-            let apic_id = Some(unsafe { (*processor).apic_id });
-            #[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
-            let apic_id = None;
-
-            processors.push(unsafe {
-                Processor {
-                    smt_id: (*processor).smt_id,
-                    core,
-                    cluster,
-                    package,
-                    linux_id,
-                    windows_group_id,
-                    windows_processor_id,
-                    apic_id,
-                    cache: Self::cache_info(&(*processor).cache),
-                }
-            })
+    fn cache_info(cache_info: &cpuinfo_processor__bindgen_ty_1, topology: &Topology) -> CacheInfo {
+        unsafe {
+            CacheInfo {
+                l1i: Self::shared_cache(cache_info.l1i, cpuinfo_get_l1i_caches(), &topology.l1i),
+                l1d: Self::shared_cache(cache_info.l1d, cpuinfo_get_l1d_caches(), &topology.l1d),
+                l2: Self::shared_cache(cache_info.l2, cpuinfo_get_l2_caches(), &topology.l2),
+                l3: Self::shared_cache(cache_info.l3, cpuinfo_get_l3_caches(), &topology.l3),
+                l4: Self::shared_cache(cache_info.l4, cpuinfo_get_l4_caches(), &topology.l4),
+            }
         }
+    }
+
+    fn processor(
+        processor: *const cpuinfo_processor,
+        core: Arc<Core>,
+        cluster: Arc<Cluster>,
+        package: Arc<Package>,
+        cache: CacheInfo,
+    ) -> Processor {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let linux_id = Some(unsafe { (*processor).linux_id });
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let linux_id = None;
+
+        #[cfg(target_os = "windows")]
+        let (windows_group_id, windows_processor_id) = {
+            (
+                Some(unsafe { (*processor).windows_group_id }),
+                Some(unsafe { (*processor).windows_processor_id }),
+            )
+        };
+        #[cfg(not(target_os = "windows"))]
+        let (windows_group_id, windows_processor_id) = (None, None);
 
-        processors
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        let apic_id = Some(unsafe { (*processor).apic_id });
+        #[cfg(all(not(target_arch = "x86_64"), not(target_arch = "x86")))]
+        let apic_id = None;
+
+        unsafe {
+            Processor {
+                smt_id: (*processor).smt_id,
+                core,
+                cluster,
+                package,
+                linux_id,
+                windows_group_id,
+                windows_processor_id,
+                apic_id,
+                cache,
+                die: None,
+                numa_node: None,
+            }
+        }
     }
 }
 
+/// Index of `item` within the `cpuinfo` array starting at `base`.
+#[cfg(not(cpuinfo_fallback))]
+unsafe fn index_of<T>(base: *const T, item: *const T) -> usize {
+    item.offset_from(base) as usize
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Cache {
@@ -281,12 +736,16 @@ pub struct Cache {
     pub partitions: u32,
     #[doc = " Line size in bytes"]
     pub line_size: u32,
-    #[doc = " Binary characteristics of the cache (unified cache, inclusive cache,\n cache with complex indexing).\n\n @see CPUINFO_CACHE_UNIFIED, CPUINFO_CACHE_INCLUSIVE,\n CPUINFO_CACHE_COMPLEX_INDEXING"]
-    pub flags: u32,
+    #[doc = " Binary characteristics of the cache (unified cache, inclusive cache,\n cache with complex indexing)."]
+    pub flags: CacheFlags,
     #[doc = " Index of the first logical processor that shares this cache"]
     pub processor_start: u32,
     #[doc = " Number of logical processors that share this cache"]
     pub processor_count: u32,
+    /// NUMA node containing every logical processor that shares this cache, see
+    /// [`Topology::numa_nodes()`].
+    #[serde(default)]
+    pub numa_node: Option<Arc<NumaNode>>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -308,26 +767,46 @@ pub struct Processor {
     #[doc = " APIC ID (unique x86-specific ID of the logical processor)"]
     pub apic_id: Option<u32>,
     pub cache: CacheInfo,
+    /// Die containing this logical processor, see [`Topology::dies()`].
+    #[serde(default)]
+    pub die: Option<Arc<Die>>,
+    /// NUMA node containing this logical processor, see [`Topology::numa_nodes()`].
+    #[serde(default)]
+    pub numa_node: Option<Arc<NumaNode>>,
 }
 
 #[repr(C)]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CacheInfo {
     #[doc = " Level 1 instruction cache"]
-    pub l1i: Option<Cache>,
+    pub l1i: Option<Arc<Cache>>,
+    #[doc = " Level 1 data cache"]
+    pub l1d: Option<Arc<Cache>>,
+    #[doc = " Level 2 unified or data cache"]
+    pub l2: Option<Arc<Cache>>,
+    #[doc = " Level 3 unified or data cache"]
+    pub l3: Option<Arc<Cache>>,
+    #[doc = " Level 4 unified or data cache"]
+    pub l4: Option<Arc<Cache>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum CacheLevel {
+    #[doc = " Level 1 instruction cache"]
+    L1i,
     #[doc = " Level 1 data cache"]
-    pub l1d: Option<Cache>,
+    L1d,
     #[doc = " Level 2 unified or data cache"]
-    pub l2: Option<Cache>,
+    L2,
     #[doc = " Level 3 unified or data cache"]
-    pub l3: Option<Cache>,
+    L3,
     #[doc = " Level 4 unified or data cache"]
-    pub l4: Option<Cache>,
+    L4,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Vendor {
-    pub vendor: cpuinfo_vendor,
+    pub vendor: VendorKind,
     pub name: Cow<'static, str>,
 }
 
@@ -348,11 +827,34 @@ pub struct Core {
     #[doc = " CPU microarchitecture for this core"]
     pub uarch: Uarch,
     #[doc = " Value of CPUID leaf 1 EAX register for this core (x86/x64-specific ID)"]
-    pub cpuid: Option<u32>,
+    pub cpuid: Option<CpuidSignature>,
     #[doc = " Value of Main ID Register (MIDR) for this core (arm/aarch64-specific ID)"]
-    pub midr: Option<u32>,
+    pub midr: Option<Midr>,
     #[doc = " Clock rate (non-Turbo) of the core, in Hz"]
     pub frequency: u64,
+    /// Role of the core on heterogeneous systems.
+    #[serde(default)]
+    pub class: CoreClass,
+    /// Die containing this core, see [`Topology::dies()`].
+    #[serde(default)]
+    pub die: Option<Arc<Die>>,
+    /// Base, boost and minimum frequencies of the core.
+    #[serde(default)]
+    pub frequency_info: FrequencyInfo,
+    /// Performance of the core relative to the other cores of the system, where the fastest core
+    /// has a capacity of `1024`.
+    ///
+    /// This is the `cpu_capacity` that Linux reports on heterogeneous systems. Elsewhere it is
+    /// estimated from a built-in table of the relative performance per clock of well-known
+    /// microarchitectures, or of each [`CoreClass`] when the microarchitecture is unknown, times
+    /// the maximum frequency of the core. Work can be split between cores proportionally to
+    /// their capacity.
+    #[serde(default = "capacity::default_capacity")]
+    pub capacity: u32,
+    /// NUMA node containing every logical processor of this core, see
+    /// [`Topology::numa_nodes()`].
+    #[serde(default)]
+    pub numa_node: Option<Arc<NumaNode>>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -374,9 +876,9 @@ pub struct Cluster {
     #[doc = " CPU microarchitecture of the cores in the cluster"]
     pub uarch: Uarch,
     #[doc = " Value of CPUID leaf 1 EAX register for this core (x86/x64-specific ID)"]
-    pub cpuid: Option<u32>,
+    pub cpuid: Option<CpuidSignature>,
     #[doc = " Value of Main ID Register (MIDR) for this core (arm/aarch64-specific ID)"]
-    pub midr: Option<u32>,
+    pub midr: Option<Midr>,
     #[doc = " Clock rate (non-Turbo) of the cores in the cluster, in Hz"]
     pub frequency: u64,
 }
@@ -402,170 +904,53 @@ pub struct Package {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Uarch {
     #[doc = " Type of CPU microarchitecture"]
-    pub uarch: cpuinfo_uarch,
+    pub uarch: UarchKind,
 
     #[doc = " Type of CPU microarchitecture as text"]
     pub name: Cow<'static, str>,
 }
 
+impl Uarch {
+    /// Vendor that designed this microarchitecture, see [`UarchKind::vendor()`].
+    pub fn vendor(&self) -> VendorKind {
+        self.uarch.vendor()
+    }
+
+    /// Family this microarchitecture belongs to.
+    pub fn family(&self) -> Option<UarchFamily> {
+        self.uarch.family()
+    }
+
+    /// Kind of core this microarchitecture is designed as.
+    pub fn class(&self) -> Option<UarchClass> {
+        self.uarch.class()
+    }
+
+    /// Returns `true` if this microarchitecture is `other` or a newer member of the same
+    /// family, see [`UarchKind::is_at_least()`].
+    pub fn is_at_least(&self, other: UarchKind) -> bool {
+        self.uarch.is_at_least(other)
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct UarchInfo {
     #[doc = " Type of CPU microarchitecture"]
     pub uarch: Uarch,
     #[doc = " Value of CPUID leaf 1 EAX register for the microarchitecture (x86/x64-specific ID)"]
-    pub cpuid: Option<u32>,
+    pub cpuid: Option<CpuidSignature>,
     #[doc = " Value of Main ID Register (MIDR) for this core (arm/aarch64-specific ID)"]
-    pub midr: Option<u32>,
+    pub midr: Option<Midr>,
     #[doc = " Number of logical processors with the microarchitecture"]
     pub processor_count: u32,
     #[doc = " Number of cores with the microarchitecture"]
     pub core_count: u32,
 }
 
-pub fn vendor_to_string(vendor: cpuinfo_vendor) -> &'static str {
-    #[allow(non_upper_case_globals)]
-    match vendor {
-        cpuinfo_vendor_cpuinfo_vendor_unknown => "unknown",
-        cpuinfo_vendor_cpuinfo_vendor_intel => "Intel",
-        cpuinfo_vendor_cpuinfo_vendor_amd => "AMD",
-        cpuinfo_vendor_cpuinfo_vendor_huawei => "Huawei",
-        cpuinfo_vendor_cpuinfo_vendor_hygon => "Hygon",
-        cpuinfo_vendor_cpuinfo_vendor_arm => "ARM",
-        cpuinfo_vendor_cpuinfo_vendor_qualcomm => "Qualcomm",
-        cpuinfo_vendor_cpuinfo_vendor_apple => "Apple",
-        cpuinfo_vendor_cpuinfo_vendor_samsung => "Samsung",
-        cpuinfo_vendor_cpuinfo_vendor_nvidia => "Nvidia",
-        cpuinfo_vendor_cpuinfo_vendor_mips => "MIPS",
-        cpuinfo_vendor_cpuinfo_vendor_ibm => "IBM",
-        cpuinfo_vendor_cpuinfo_vendor_ingenic => "Ingenic",
-        cpuinfo_vendor_cpuinfo_vendor_via => "VIA",
-        cpuinfo_vendor_cpuinfo_vendor_cavium => "Cavium",
-        cpuinfo_vendor_cpuinfo_vendor_broadcom => "Broadcom",
-        cpuinfo_vendor_cpuinfo_vendor_apm => "Applied Micro",
-        _ => "<unknown>",
-    }
+pub fn vendor_to_string(vendor: u32) -> &'static str {
+    VendorKind::from_raw(vendor).map_or("<unknown>", VendorKind::name)
 }
 
-pub fn uarch_to_string(uarch: cpuinfo_uarch) -> &'static str {
-    #[allow(non_upper_case_globals)]
-    match uarch {
-        cpuinfo_uarch_cpuinfo_uarch_unknown => "unknown",
-        cpuinfo_uarch_cpuinfo_uarch_p5 => "P5",
-        cpuinfo_uarch_cpuinfo_uarch_quark => "Quark",
-        cpuinfo_uarch_cpuinfo_uarch_p6 => "P6",
-        cpuinfo_uarch_cpuinfo_uarch_dothan => "Dothan",
-        cpuinfo_uarch_cpuinfo_uarch_yonah => "Yonah",
-        cpuinfo_uarch_cpuinfo_uarch_conroe => "Conroe",
-        cpuinfo_uarch_cpuinfo_uarch_penryn => "Penryn",
-        cpuinfo_uarch_cpuinfo_uarch_nehalem => "Nehalem",
-        cpuinfo_uarch_cpuinfo_uarch_sandy_bridge => "Sandy Bridge",
-        cpuinfo_uarch_cpuinfo_uarch_ivy_bridge => "Ivy Bridge",
-        cpuinfo_uarch_cpuinfo_uarch_haswell => "Haswell",
-        cpuinfo_uarch_cpuinfo_uarch_broadwell => "Broadwell",
-        cpuinfo_uarch_cpuinfo_uarch_sky_lake => "Sky Lake",
-        cpuinfo_uarch_cpuinfo_uarch_palm_cove => "Palm Cove",
-        cpuinfo_uarch_cpuinfo_uarch_sunny_cove => "Sunny Cove",
-        cpuinfo_uarch_cpuinfo_uarch_willamette => "Willamette",
-        cpuinfo_uarch_cpuinfo_uarch_prescott => "Prescott",
-        cpuinfo_uarch_cpuinfo_uarch_bonnell => "Bonnell",
-        cpuinfo_uarch_cpuinfo_uarch_saltwell => "Saltwell",
-        cpuinfo_uarch_cpuinfo_uarch_silvermont => "Silvermont",
-        cpuinfo_uarch_cpuinfo_uarch_airmont => "Airmont",
-        cpuinfo_uarch_cpuinfo_uarch_goldmont => "Goldmont",
-        cpuinfo_uarch_cpuinfo_uarch_goldmont_plus => "Goldmont Plus",
-        cpuinfo_uarch_cpuinfo_uarch_knights_ferry => "Knights Ferry",
-        cpuinfo_uarch_cpuinfo_uarch_knights_corner => "Knights Corner",
-        cpuinfo_uarch_cpuinfo_uarch_knights_landing => "Knights Landing",
-        cpuinfo_uarch_cpuinfo_uarch_knights_hill => "Knights Hill",
-        cpuinfo_uarch_cpuinfo_uarch_knights_mill => "Knights Mill",
-        cpuinfo_uarch_cpuinfo_uarch_k5 => "K5",
-        cpuinfo_uarch_cpuinfo_uarch_k6 => "K6",
-        cpuinfo_uarch_cpuinfo_uarch_k7 => "K7",
-        cpuinfo_uarch_cpuinfo_uarch_k8 => "K8",
-        cpuinfo_uarch_cpuinfo_uarch_k10 => "K10",
-        cpuinfo_uarch_cpuinfo_uarch_bulldozer => "Bulldozer",
-        cpuinfo_uarch_cpuinfo_uarch_piledriver => "Piledriver",
-        cpuinfo_uarch_cpuinfo_uarch_steamroller => "Steamroller",
-        cpuinfo_uarch_cpuinfo_uarch_excavator => "Excavator",
-        cpuinfo_uarch_cpuinfo_uarch_zen => "Zen",
-        cpuinfo_uarch_cpuinfo_uarch_zen2 => "Zen 2",
-        cpuinfo_uarch_cpuinfo_uarch_zen3 => "Zen 3",
-        cpuinfo_uarch_cpuinfo_uarch_zen4 => "Zen 4",
-        cpuinfo_uarch_cpuinfo_uarch_geode => "Geode",
-        cpuinfo_uarch_cpuinfo_uarch_bobcat => "Bobcat",
-        cpuinfo_uarch_cpuinfo_uarch_jaguar => "Jaguar",
-        cpuinfo_uarch_cpuinfo_uarch_puma => "Puma",
-        cpuinfo_uarch_cpuinfo_uarch_xscale => "XScale",
-        cpuinfo_uarch_cpuinfo_uarch_arm7 => "ARM7",
-        cpuinfo_uarch_cpuinfo_uarch_arm9 => "ARM9",
-        cpuinfo_uarch_cpuinfo_uarch_arm11 => "ARM11",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a5 => "Cortex-A5",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a7 => "Cortex-A7",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a8 => "Cortex-A8",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a9 => "Cortex-A9",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a12 => "Cortex-A12",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a15 => "Cortex-A15",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a17 => "Cortex-A17",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a32 => "Cortex-A32",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a35 => "Cortex-A35",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a53 => "Cortex-A53",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a55r0 => "Cortex-A55r0",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a55 => "Cortex-A55",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a57 => "Cortex-A57",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a65 => "Cortex-A65",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a72 => "Cortex-A72",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a73 => "Cortex-A73",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a75 => "Cortex-A75",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a76 => "Cortex-A76",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a77 => "Cortex-A77",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a78 => "Cortex-A78",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a510 => "Cortex-A510",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a710 => "Cortex-A710",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_a715 => "Cortex-A715",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_x1 => "Cortex-X1",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_x2 => "Cortex-X2",
-        cpuinfo_uarch_cpuinfo_uarch_cortex_x3 => "Cortex-X3",
-        cpuinfo_uarch_cpuinfo_uarch_neoverse_n1 => "Neoverse N1",
-        cpuinfo_uarch_cpuinfo_uarch_neoverse_e1 => "Neoverse E1",
-        cpuinfo_uarch_cpuinfo_uarch_neoverse_v1 => "Neoverse V1",
-        cpuinfo_uarch_cpuinfo_uarch_neoverse_n2 => "Neoverse N2",
-        cpuinfo_uarch_cpuinfo_uarch_neoverse_v2 => "Neoverse V2",
-        cpuinfo_uarch_cpuinfo_uarch_scorpion => "Scorpion",
-        cpuinfo_uarch_cpuinfo_uarch_krait => "Krait",
-        cpuinfo_uarch_cpuinfo_uarch_kryo => "Kryo",
-        cpuinfo_uarch_cpuinfo_uarch_falkor => "Falkor",
-        cpuinfo_uarch_cpuinfo_uarch_saphira => "Saphira",
-        cpuinfo_uarch_cpuinfo_uarch_denver => "Denver",
-        cpuinfo_uarch_cpuinfo_uarch_denver2 => "Denver 2",
-        cpuinfo_uarch_cpuinfo_uarch_carmel => "Carmel",
-        cpuinfo_uarch_cpuinfo_uarch_exynos_m1 => "Exynos M1",
-        cpuinfo_uarch_cpuinfo_uarch_exynos_m2 => "Exynos M2",
-        cpuinfo_uarch_cpuinfo_uarch_exynos_m3 => "Exynos M3",
-        cpuinfo_uarch_cpuinfo_uarch_exynos_m4 => "Exynos M4",
-        cpuinfo_uarch_cpuinfo_uarch_exynos_m5 => "Exynos M5",
-        cpuinfo_uarch_cpuinfo_uarch_swift => "Swift",
-        cpuinfo_uarch_cpuinfo_uarch_cyclone => "Cyclone",
-        cpuinfo_uarch_cpuinfo_uarch_typhoon => "Typhoon",
-        cpuinfo_uarch_cpuinfo_uarch_twister => "Twister",
-        cpuinfo_uarch_cpuinfo_uarch_hurricane => "Hurricane",
-        cpuinfo_uarch_cpuinfo_uarch_monsoon => "Monsoon",
-        cpuinfo_uarch_cpuinfo_uarch_mistral => "Mistral",
-        cpuinfo_uarch_cpuinfo_uarch_vortex => "Vortex",
-        cpuinfo_uarch_cpuinfo_uarch_tempest => "Tempest",
-        cpuinfo_uarch_cpuinfo_uarch_lightning => "Lightning",
-        cpuinfo_uarch_cpuinfo_uarch_thunder => "Thunder",
-        cpuinfo_uarch_cpuinfo_uarch_firestorm => "Firestorm",
-        cpuinfo_uarch_cpuinfo_uarch_icestorm => "Icestorm",
-        cpuinfo_uarch_cpuinfo_uarch_avalanche => "Avalanche",
-        cpuinfo_uarch_cpuinfo_uarch_blizzard => "Blizzard",
-        cpuinfo_uarch_cpuinfo_uarch_thunderx => "ThunderX",
-        cpuinfo_uarch_cpuinfo_uarch_thunderx2 => "ThunderX2",
-        cpuinfo_uarch_cpuinfo_uarch_pj4 => "PJ4",
-        cpuinfo_uarch_cpuinfo_uarch_brahma_b15 => "Brahma B15",
-        cpuinfo_uarch_cpuinfo_uarch_brahma_b53 => "Brahma B53",
-        cpuinfo_uarch_cpuinfo_uarch_xgene => "X-Gene",
-        cpuinfo_uarch_cpuinfo_uarch_dhyana => "Dhyana",
-        cpuinfo_uarch_cpuinfo_uarch_taishan_v110 => "TaiShan v110",
-        _ => "<unknown>",
-    }
+pub fn uarch_to_string(uarch: u32) -> &'static str {
+    UarchKind::from_raw(uarch).map_or("<unknown>", UarchKind::name)
 }
@@ -0,0 +1,180 @@
+use std::path::{Path, PathBuf};
+
+use crate::sysfs::{parse_cpu_list, read};
+use crate::{CpuSet, Processor, Topology};
+
+/// Restrictions on the logical processors that a process may use, from its CPU affinity and the
+/// Linux control groups (cgroups) it runs in, for example inside a container.
+///
+/// ```no_run
+/// use cpuinfo_rs::{CpuInfo, CpuLimits};
+///
+/// let info = CpuInfo::new();
+/// let limits = CpuLimits::current();
+/// let workers = info.topology().effective_parallelism(&limits);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CpuLimits {
+    affinity: Option<CpuSet>,
+    cpuset: Option<CpuSet>,
+    quota: Option<f64>,
+}
+
+impl CpuLimits {
+    /// Limits of the current process, using the affinity of the calling thread.
+    ///
+    /// A thread that was pinned to a single processor, such as a worker of a thread pool, is only
+    /// allowed to run on that processor. Read the limits before pinning any threads to size a
+    /// thread pool.
+    pub fn current() -> Self {
+        Self {
+            affinity: CpuSet::of_current_thread().ok(),
+            ..Self::from_root("/")
+        }
+    }
+
+    /// Reads the cgroup limits of the current process from a file system mounted at `root`,
+    /// which should contain `proc/self/cgroup` and the hierarchies under `sys/fs/cgroup`.
+    ///
+    /// This can be pointed at a copy of those files to inspect the limits of another system. The
+    /// affinity is not part of the file system and is left unrestricted.
+    pub fn from_root(root: impl AsRef<Path>) -> Self {
+        let root = root.as_ref();
+        let mut cpuset = None;
+        let mut quota = None;
+
+        // Every line is `hierarchy-ID:controller-list:cgroup-path`, where the cgroup v2 hierarchy
+        // has an empty controller list.
+        for line in read(root.join("proc/self/cgroup"))
+            .unwrap_or_default()
+            .lines()
+        {
+            let mut fields = line.splitn(3, ':').skip(1);
+            let (Some(controllers), Some(path)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            let path = path.trim_start_matches('/');
+
+            if controllers.is_empty() {
+                let dirs = cgroup_dirs(&root.join("sys/fs/cgroup"), path);
+                cpuset = cpuset.or_else(|| cgroup_cpuset(&dirs, &["cpuset.cpus.effective"]));
+                quota = min_quota(quota, dirs.iter().filter_map(|dir| v2_quota(dir)));
+                continue;
+            }
+
+            let dirs = cgroup_dirs(&root.join("sys/fs/cgroup").join(controllers), path);
+            for controller in controllers.split(',') {
+                match controller {
+                    "cpuset" => {
+                        cpuset = cpuset.or_else(|| {
+                            cgroup_cpuset(&dirs, &["cpuset.effective_cpus", "cpuset.cpus"])
+                        });
+                    }
+                    "cpu" => quota = min_quota(quota, dirs.iter().filter_map(|dir| v1_quota(dir))),
+                    _ => {}
+                }
+            }
+        }
+
+        Self {
+            affinity: None,
+            cpuset: cpuset.map(CpuSet::from_linux_ids),
+            quota,
+        }
+    }
+
+    /// Processors that the calling thread is allowed to run on, or [`None`] when unknown.
+    pub fn affinity(&self) -> Option<&CpuSet> {
+        self.affinity.as_ref()
+    }
+
+    /// Processors of the cgroup `cpuset` controller, or [`None`] when the cgroup doesn't restrict
+    /// them.
+    pub fn cpuset(&self) -> Option<&CpuSet> {
+        self.cpuset.as_ref()
+    }
+
+    /// CPU time that the cgroup may use per period of the CFS bandwidth controller, in number of
+    /// logical processors, or [`None`] without a quota.
+    pub fn quota(&self) -> Option<f64> {
+        self.quota
+    }
+
+    fn allows(&self, processor: &Processor) -> bool {
+        // Processors can only be matched by their Linux ID.
+        processor.linux_id.is_none()
+            || [&self.affinity, &self.cpuset]
+                .into_iter()
+                .flatten()
+                .all(|set| set.contains(processor))
+    }
+}
+
+impl Topology {
+    /// Logical processors that a process with the given `limits` may run on.
+    pub fn allowed_processors(&self, limits: &CpuLimits) -> Vec<Processor> {
+        self.processors()
+            .iter()
+            .filter(|processor| limits.allows(processor))
+            .cloned()
+            .collect()
+    }
+
+    /// Number of threads that a process with the given `limits` can keep busy, which is the number
+    /// of [allowed processors](Self::allowed_processors()) capped by the CFS quota, and at least
+    /// `1`.
+    pub fn effective_parallelism(&self, limits: &CpuLimits) -> usize {
+        let processors = self.allowed_processors(limits).len();
+        let quota = limits.quota.map_or(usize::MAX, |quota| quota as usize);
+
+        processors.min(quota).max(1)
+    }
+}
+
+/// Directory of the cgroup at `path` in the hierarchy mounted at `mount`, followed by its
+/// ancestors within the hierarchy.
+fn cgroup_dirs(mount: &Path, path: &str) -> Vec<PathBuf> {
+    let mut dir = mount.join(path);
+    // Containers usually mount their own cgroup as the root of the hierarchy, while
+    // `/proc/self/cgroup` still shows its path on the host.
+    if !dir.is_dir() {
+        dir = mount.to_path_buf();
+    }
+
+    dir.ancestors()
+        .take_while(|dir| dir.starts_with(mount))
+        .map(Path::to_path_buf)
+        .collect()
+}
+
+/// Processors in the first of `files` found in the closest cgroup that has the `cpuset` controller
+/// enabled.
+fn cgroup_cpuset(dirs: &[PathBuf], files: &[&str]) -> Option<Vec<i32>> {
+    dirs.iter()
+        .find_map(|dir| files.iter().find_map(|file| read(dir.join(file))))
+        .and_then(|list| parse_cpu_list(&list))
+        .filter(|ids| !ids.is_empty())
+}
+
+/// Quota from `cpu.max`, which holds `$MAX $PERIOD` or `max $PERIOD`.
+fn v2_quota(dir: &Path) -> Option<f64> {
+    let max = read(dir.join("cpu.max"))?;
+    let (quota, period) = max.split_once(' ')?;
+    ratio(quota.parse().ok()?, period.parse().ok()?)
+}
+
+/// Quota from `cpu.cfs_quota_us`, which is `-1` without a quota, and `cpu.cfs_period_us`.
+fn v1_quota(dir: &Path) -> Option<f64> {
+    let quota = read(dir.join("cpu.cfs_quota_us"))?.parse::<i64>().ok()?;
+    let period = read(dir.join("cpu.cfs_period_us"))?.parse().ok()?;
+    ratio(u64::try_from(quota).ok()?, period)
+}
+
+fn ratio(quota: u64, period: u64) -> Option<f64> {
+    (period > 0).then(|| quota as f64 / period as f64)
+}
+
+/// The tightest of the quotas, as every cgroup is limited by the quotas of its ancestors.
+fn min_quota(quota: Option<f64>, quotas: impl Iterator<Item = f64>) -> Option<f64> {
+    quotas.chain(quota).reduce(f64::min)
+}
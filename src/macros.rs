@@ -0,0 +1,82 @@
+/// Defines a fieldless enum mirroring one of the C enums of `cpuinfo`, together with conversions
+/// from and to the raw value and a human-readable name.
+///
+/// Every enum must contain an `Unknown` variant, which raw values that are not known to this crate
+/// convert to.
+macro_rules! cpuinfo_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $raw:expr, $display:literal;)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(u32)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant = $raw,)*
+        }
+
+        impl $name {
+            /// Every value known to this crate.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            /// Converts a raw `cpuinfo` value, returning [`None`] if it is not known to this crate.
+            pub fn from_raw(raw: u32) -> Option<Self> {
+                Self::ALL.iter().copied().find(|kind| kind.to_raw() == raw)
+            }
+
+            /// Raw `cpuinfo` value.
+            pub fn to_raw(self) -> u32 {
+                self as u32
+            }
+
+            /// Human-readable name.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $display,)*
+                }
+            }
+
+            fn ident(self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)*
+                }
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(raw: u32) -> Self {
+                Self::from_raw(raw).unwrap_or(Self::Unknown)
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(kind: $name) -> Self {
+                kind.to_raw()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.name())
+            }
+        }
+
+        /// Parses either the human-readable name or the name of the variant, ignoring case.
+        impl std::str::FromStr for $name {
+            type Err = $crate::ParseKindError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::ALL
+                    .iter()
+                    .copied()
+                    .find(|kind| {
+                        kind.name().eq_ignore_ascii_case(s) || kind.ident().eq_ignore_ascii_case(s)
+                    })
+                    .ok_or_else(|| $crate::ParseKindError { name: s.to_owned() })
+            }
+        }
+    };
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+
+/// Value of the Main ID Register (arm/aarch64-specific).
+///
+/// Serializes as the raw register value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Midr(u32);
+
+impl Midr {
+    pub fn new(midr: u32) -> Self {
+        Self(midr)
+    }
+
+    /// Raw value of the register.
+    pub fn raw(self) -> u32 {
+        self.0
+    }
+
+    /// Implementer code, see [`Midr::implementer_name()`].
+    pub fn implementer(self) -> u8 {
+        (self.0 >> 24) as u8
+    }
+
+    /// Major revision of the part, the `r` in `r1p2`.
+    pub fn variant(self) -> u8 {
+        ((self.0 >> 20) & 0xf) as u8
+    }
+
+    /// Architecture code, `0xF` when the architecture is defined by the ID registers.
+    pub fn architecture(self) -> u8 {
+        ((self.0 >> 16) & 0xf) as u8
+    }
+
+    /// Implementer-defined part number.
+    pub fn part(self) -> u16 {
+        ((self.0 >> 4) & 0xfff) as u16
+    }
+
+    /// Minor revision of the part, the `p` in `r1p2`.
+    pub fn revision(self) -> u8 {
+        (self.0 & 0xf) as u8
+    }
+
+    /// Name of the implementer, if it is known to this crate.
+    pub fn implementer_name(self) -> Option<&'static str> {
+        Some(match self.implementer() {
+            0x41 => "ARM",
+            0x42 => "Broadcom",
+            0x43 => "Cavium",
+            0x44 => "DEC",
+            0x46 => "Fujitsu",
+            0x48 => "HiSilicon",
+            0x49 => "Infineon",
+            0x4d => "Motorola",
+            0x4e => "NVIDIA",
+            0x50 => "Applied Micro",
+            0x51 => "Qualcomm",
+            0x53 => "Samsung",
+            0x56 => "Marvell",
+            0x61 => "Apple",
+            0x66 => "Faraday",
+            0x69 => "Intel",
+            0x6d => "Microsoft",
+            0x70 => "Phytium",
+            0xc0 => "Ampere",
+            _ => return None,
+        })
+    }
+}
+
+impl From<u32> for Midr {
+    fn from(midr: u32) -> Self {
+        Self::new(midr)
+    }
+}
+
+impl From<Midr> for u32 {
+    fn from(midr: Midr) -> Self {
+        midr.raw()
+    }
+}
+
+/// Formats the revision of the part as `r1p2`.
+impl fmt::Display for Midr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "r{}p{}", self.variant(), self.revision())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cortex_a76() {
+        let midr = Midr::new(0x414fd0b1);
+        assert_eq!(midr.implementer(), 0x41);
+        assert_eq!(midr.implementer_name(), Some("ARM"));
+        assert_eq!(midr.variant(), 4);
+        assert_eq!(midr.architecture(), 0xf);
+        assert_eq!(midr.part(), 0xd0b);
+        assert_eq!(midr.revision(), 1);
+        assert_eq!(midr.to_string(), "r4p1");
+    }
+
+    #[test]
+    fn neoverse_n1() {
+        let midr = Midr::new(0x413fd0c1);
+        assert_eq!(midr.implementer_name(), Some("ARM"));
+        assert_eq!(midr.variant(), 3);
+        assert_eq!(midr.part(), 0xd0c);
+        assert_eq!(midr.revision(), 1);
+        assert_eq!(midr.to_string(), "r3p1");
+    }
+
+    #[test]
+    fn unknown_implementer() {
+        let midr = Midr::new(0x00000000);
+        assert_eq!(midr.implementer_name(), None);
+        assert_eq!(midr.to_string(), "r0p0");
+    }
+}
@@ -0,0 +1,254 @@
+// Replays captured `/proc` and `/sys` contents through the mock support of `cpuinfo`, which is
+// compiled in by the `mock` feature. The declarations below mirror `cpuinfo-mock.h`, which isn't
+// part of the generated bindings.
+
+use std::ffi::{c_char, CString};
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::CpuInfoError;
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct cpuinfo_mock_file {
+    path: *const c_char,
+    size: usize,
+    content: *const c_char,
+    offset: usize,
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct cpuinfo_mock_cpuid {
+    input_eax: u32,
+    input_ecx: u32,
+    eax: u32,
+    ebx: u32,
+    ecx: u32,
+    edx: u32,
+}
+
+extern "C" {
+    fn cpuinfo_mock_filesystem(files: *mut cpuinfo_mock_file);
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    fn cpuinfo_mock_set_cpuid(dump: *mut cpuinfo_mock_cpuid, entries: usize);
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+    fn cpuinfo_set_hwcap(hwcap: u32);
+    #[cfg(target_arch = "aarch64")]
+    fn cpuinfo_set_hwcap2(hwcap2: u64);
+    #[cfg(target_arch = "arm")]
+    fn cpuinfo_set_hwcap2(hwcap2: u32);
+}
+
+/// Paths and contents of mocked files.
+type Files = Vec<(String, Vec<u8>)>;
+
+/// Files of the installed mock, which the passes of this crate that read `/proc` and `/sys`
+/// themselves see instead of the real files, or [`None`] once the system was read without a mock.
+///
+/// Installing a mock and reading the system both claim this slot, so a mock can't be installed
+/// while a [`crate::CpuInfo`] is being created on another thread.
+static INSTALLED: OnceLock<Option<Files>> = OnceLock::new();
+
+/// Files of the installed [`MockSystem`], or [`None`] when no mock is installed.
+pub(crate) fn installed_files() -> Option<&'static [(String, Vec<u8>)]> {
+    INSTALLED.get()?.as_deref()
+}
+
+/// Keeps mocks from being installed after the system was read, called before initializing
+/// `cpuinfo`.
+pub(crate) fn seal() {
+    INSTALLED.get_or_init(|| None);
+}
+
+/// Result of a single `cpuid` invocation, returned to `cpuinfo` instead of executing the
+/// instruction.
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MockCpuid {
+    /// Leaf, passed to `cpuid` in `eax`.
+    pub leaf: u32,
+    /// Subleaf, passed to `cpuid` in `ecx`.
+    pub subleaf: u32,
+    pub eax: u32,
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+}
+
+/// Captured system files (and `cpuid` results on x86) that `cpuinfo` reads instead of the real
+/// ones.
+///
+/// The mock has to be installed before the first [`crate::CpuInfo`] is created, as `cpuinfo` is
+/// only initialized once per process. Tests that replay different systems therefore need to run
+/// in separate processes, for example as separate integration test targets.
+///
+/// The data is interpreted by the `cpuinfo` backend of the target the crate is compiled for, so
+/// captures of ARM devices only replay on an ARM target (natively or under emulation), and x86
+/// captures only on an x86 target.
+///
+/// While the `mock` feature is enabled, `cpuinfo` reads nothing from the real system, so every
+/// [`crate::CpuInfo`] in the process needs an installed mock.
+///
+/// ```no_run
+/// use cpuinfo_rs::{CpuInfo, MockSystem};
+///
+/// // On x86, the `cpuid` results of the captured system are added with `with_cpuid()`.
+/// MockSystem::from_dir("tests/captures/epyc-7742-2s")
+///     .unwrap()
+///     .install()
+///     .unwrap();
+///
+/// let info = CpuInfo::new();
+/// assert_eq!(info.packages().len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockSystem {
+    files: Files,
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    cpuid: Vec<MockCpuid>,
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+    hwcap: u32,
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+    hwcap2: u64,
+}
+
+impl MockSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads every file below `root`, which is treated as the root of the filesystem. A capture
+    /// of `/proc/cpuinfo` is thus expected at `{root}/proc/cpuinfo`, and the contents of
+    /// `/sys/devices/system/cpu` at `{root}/sys/devices/system/cpu`.
+    pub fn from_dir(root: impl AsRef<Path>) -> io::Result<Self> {
+        let mut mock = Self::new();
+        mock.load_dir(root.as_ref(), "")?;
+        Ok(mock)
+    }
+
+    fn load_dir(&mut self, dir: &Path, prefix: &str) -> io::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = format!("{prefix}/{}", entry.file_name().to_string_lossy());
+
+            if entry.file_type()?.is_dir() {
+                self.load_dir(&entry.path(), &path)?;
+            } else {
+                self.files.push((path, std::fs::read(entry.path())?));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds a file at the absolute `path`, for example `/sys/devices/system/cpu/possible`,
+    /// replacing an earlier file with the same path.
+    pub fn with_file(mut self, path: impl Into<String>, content: impl Into<Vec<u8>>) -> Self {
+        let path = path.into();
+        self.files.retain(|(existing, _)| *existing != path);
+        self.files.push((path, content.into()));
+        self
+    }
+
+    /// Adds the results that `cpuid` returns for the given leaves and subleaves. Leaves that are
+    /// not in the dump return zero in all registers.
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    pub fn with_cpuid(mut self, dump: impl IntoIterator<Item = MockCpuid>) -> Self {
+        self.cpuid.extend(dump);
+        self
+    }
+
+    /// Sets the `AT_HWCAP` bits that `cpuinfo` uses instead of `getauxval()`, which it relies on
+    /// for most ISA features. The bits are `0` unless they are set.
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+    pub fn with_hwcap(mut self, hwcap: u32) -> Self {
+        self.hwcap = hwcap;
+        self
+    }
+
+    /// Sets the `AT_HWCAP2` bits that `cpuinfo` uses instead of `getauxval()`. Only the lower 32
+    /// bits are used on 32-bit ARM.
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+    pub fn with_hwcap2(mut self, hwcap2: u64) -> Self {
+        self.hwcap2 = hwcap2;
+        self
+    }
+
+    /// Makes `cpuinfo` read from this mock instead of the real system.
+    ///
+    /// This must be called before any thread creates a [`crate::CpuInfo`], and returns
+    /// [`CpuInfoError::AlreadyInitialized`] otherwise. The data is leaked, as `cpuinfo` keeps
+    /// referring to it for the lifetime of the process.
+    pub fn install(self) -> Result<(), CpuInfoError> {
+        let mut installed = false;
+        INSTALLED.get_or_init(|| {
+            installed = true;
+            let files = self.files.clone();
+            self.replay();
+            Some(files)
+        });
+
+        if installed {
+            Ok(())
+        } else {
+            Err(CpuInfoError::AlreadyInitialized)
+        }
+    }
+
+    /// Hands the files, `cpuid` results and `AT_HWCAP` bits to `cpuinfo`.
+    fn replay(self) {
+        // `cpuinfo` finds the end of the list through an entry without a path.
+        let mut files = self
+            .files
+            .into_iter()
+            .map(|(path, content)| {
+                let size = content.len();
+                let path = CString::new(path).expect("Mock file path contains a nul byte");
+                cpuinfo_mock_file {
+                    path: path.into_raw(),
+                    size,
+                    content: Box::leak(content.into_boxed_slice()).as_ptr().cast(),
+                    offset: 0,
+                }
+            })
+            .collect::<Vec<_>>();
+        files.push(cpuinfo_mock_file {
+            path: std::ptr::null(),
+            size: 0,
+            content: std::ptr::null(),
+            offset: 0,
+        });
+
+        unsafe { cpuinfo_mock_filesystem(Box::leak(files.into_boxed_slice()).as_mut_ptr()) };
+
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        {
+            let dump = self
+                .cpuid
+                .iter()
+                .map(|cpuid| cpuinfo_mock_cpuid {
+                    input_eax: cpuid.leaf,
+                    input_ecx: cpuid.subleaf,
+                    eax: cpuid.eax,
+                    ebx: cpuid.ebx,
+                    ecx: cpuid.ecx,
+                    edx: cpuid.edx,
+                })
+                .collect::<Vec<_>>();
+            let dump = Box::leak(dump.into_boxed_slice());
+            unsafe { cpuinfo_mock_set_cpuid(dump.as_mut_ptr(), dump.len()) };
+        }
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+        unsafe {
+            cpuinfo_set_hwcap(self.hwcap);
+            #[cfg(target_arch = "aarch64")]
+            cpuinfo_set_hwcap2(self.hwcap2);
+            #[cfg(target_arch = "arm")]
+            cpuinfo_set_hwcap2(self.hwcap2 as u32);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::{Processor, Topology};
+
+/// NUMA node: a group of logical processors that share the same memory controllers, and the
+/// memory attached to them.
+///
+/// Memory local to a node is faster to access from its processors than memory of other nodes,
+/// which matters on multi-socket systems and on chiplet processors configured with multiple nodes
+/// per socket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct NumaNode {
+    /// Linux node ID, as in `/sys/devices/system/node/node<id>`.
+    pub id: u32,
+    /// Memory attached to the node in bytes, `0` for nodes without memory.
+    pub memory: u64,
+    /// Indices of the logical processors of the node in [`Topology::processors()`], which don't
+    /// have to be contiguous.
+    pub processors: Vec<u32>,
+}
+
+impl Topology {
+    /// NUMA nodes of the system, ordered by their ID.
+    ///
+    /// This is empty on platforms other than Linux and Android, and on kernels without NUMA
+    /// support.
+    pub fn numa_nodes(&self) -> &[Arc<NumaNode>] {
+        &self.numa_nodes
+    }
+
+    /// Logical processors in `node`.
+    pub fn numa_node_processors(&self, node: &NumaNode) -> Vec<Processor> {
+        node.processors
+            .iter()
+            .filter_map(|&i| self.processors.get(i as usize))
+            .cloned()
+            .collect()
+    }
+
+    /// Fills in [`Topology::numa_nodes()`] and [`Processor::numa_node`] for a topology that was
+    /// just read from the system.
+    pub(crate) fn with_numa_nodes(self) -> Self {
+        self.with_numa_nodes_from_root("/")
+    }
+
+    /// Reads the NUMA nodes from a file system mounted at `root`, which should contain
+    /// `sys/devices/system/node`. Processors that are in no node keep no [`NumaNode`].
+    fn with_numa_nodes_from_root(mut self, root: impl AsRef<Path>) -> Self {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let nodes = sys::nodes(root.as_ref());
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let nodes: Vec<(u32, u64, Vec<i32>)> = {
+            let _ = root;
+            vec![]
+        };
+
+        self.numa_nodes = nodes
+            .into_iter()
+            .map(|(id, memory, linux_ids)| {
+                let processors = (0..self.processors.len() as u32)
+                    .filter(|&i| {
+                        self.processors[i as usize]
+                            .linux_id
+                            .is_some_and(|id| linux_ids.contains(&id))
+                    })
+                    .collect();
+
+                Arc::new(NumaNode {
+                    id,
+                    memory,
+                    processors,
+                })
+            })
+            .collect();
+        self
+    }
+}
+
+impl Processor {
+    /// NUMA node containing this logical processor, or [`None`] when the system doesn't report
+    /// NUMA nodes. This is the same as the [`Processor::numa_node`] field.
+    pub fn numa_node(&self) -> Option<&Arc<NumaNode>> {
+        self.numa_node.as_ref()
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod sys {
+    use std::path::Path;
+
+    use crate::sysfs::{parse_cpu_list, read};
+
+    /// ID, memory size and Linux processor IDs of every online node.
+    pub(super) fn nodes(root: &Path) -> Vec<(u32, u64, Vec<i32>)> {
+        let node_dir = root.join("sys/devices/system/node");
+        let Some(ids) = read(node_dir.join("online")).and_then(|list| parse_cpu_list(&list)) else {
+            return vec![];
+        };
+
+        ids.into_iter()
+            .filter_map(|id| {
+                let dir = node_dir.join(format!("node{id}"));
+                let linux_ids = parse_cpu_list(&read(dir.join("cpulist"))?)?;
+
+                Some((id as u32, memory(&dir).unwrap_or(0), linux_ids))
+            })
+            .collect()
+    }
+
+    /// Parses the `Node 0 MemTotal:       65843300 kB` line of the `meminfo` of a node.
+    fn memory(dir: &Path) -> Option<u64> {
+        read(dir.join("meminfo"))?.lines().find_map(|line| {
+            let (_, total) = line.split_once("MemTotal:")?;
+            let kb = total
+                .trim()
+                .strip_suffix("kB")?
+                .trim()
+                .parse::<u64>()
+                .ok()?;
+            Some(kb * 1024)
+        })
+    }
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod tests {
+    use super::*;
+    use crate::topology::TestCore;
+    use crate::UarchKind;
+
+    #[test]
+    fn from_root() {
+        let root = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/numa");
+        let topology = Topology::for_tests(&[TestCore::new(UarchKind::Zen4); 5])
+            .with_numa_nodes_from_root(root)
+            .relink()
+            .unwrap();
+
+        let nodes = topology.numa_nodes();
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(
+            *nodes[0],
+            NumaNode {
+                id: 0,
+                memory: 65_843_300 * 1024,
+                processors: vec![0, 1],
+            }
+        );
+        // Node 2 has no memory, and node 1 is offline.
+        assert_eq!(
+            *nodes[1],
+            NumaNode {
+                id: 2,
+                memory: 0,
+                processors: vec![2, 3],
+            }
+        );
+
+        let processors = topology.processors();
+        assert_eq!(processors[1].numa_node().map(|node| node.id), Some(0));
+        assert_eq!(processors[3].numa_node().map(|node| node.id), Some(2));
+        // Processor 4 isn't in any node.
+        assert!(processors[4].numa_node().is_none());
+    }
+
+    #[test]
+    fn without_numa() {
+        let topology = Topology::for_tests(&[TestCore::new(UarchKind::Zen4); 2])
+            .with_numa_nodes_from_root(env!("CARGO_MANIFEST_DIR"))
+            .relink()
+            .unwrap();
+
+        assert!(topology.numa_nodes().is_empty());
+        assert!(topology.processors()[0].numa_node().is_none());
+    }
+}
@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use crate::{Core, CoreClass, Processor, Topology};
+
+/// Which logical processors a thread pool should use, see [`Topology::placement()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum PlacementPolicy {
+    /// One worker on the first hardware thread of every physical core.
+    PerCore,
+    /// One worker per logical processor, starting with the first hardware thread of every core
+    /// before using their siblings.
+    PerProcessor,
+    /// One worker per prime and performance core, skipping efficiency cores on heterogeneous
+    /// systems. Systems without performance cores use their fastest class of cores.
+    PerformanceCores,
+    /// One worker per L3 cache, so that workers don't compete for the same last level cache. Every
+    /// package is treated as a single domain when the system doesn't report an L3 cache.
+    PerL3,
+}
+
+impl Topology {
+    /// Picks the logical processors that a thread pool with the given `policy` should run on,
+    /// leaving `reserved_cores` physical cores free for other work.
+    ///
+    /// Cores are preferred by their [`CoreClass`], and in topology order within a class.
+    /// Reserved cores are the least preferred of the cores that the policy selects from, and at
+    /// least one core is always kept. The number of returned processors is the number of workers
+    /// to spawn, and each one can be pinned to with [`Processor::pin_current_thread()`].
+    pub fn placement(&self, policy: PlacementPolicy, reserved_cores: usize) -> Vec<Processor> {
+        let mut cores = self.cores().to_vec();
+        cores.sort_by_key(|core| core.class);
+
+        if policy == PlacementPolicy::PerformanceCores {
+            // Systems without performance cores use their fastest class of cores instead.
+            let slowest = cores
+                .first()
+                .map_or(CoreClass::Performance, |core| core.class)
+                .max(CoreClass::Performance);
+            cores.retain(|core| core.class <= slowest);
+        }
+        cores.truncate(cores.len().saturating_sub(reserved_cores).max(1));
+
+        match policy {
+            PlacementPolicy::PerCore | PlacementPolicy::PerformanceCores => {
+                self.first_processors(&cores)
+            }
+            PlacementPolicy::PerProcessor => {
+                let threads = cores.iter().map(|core| core.processor_count).max();
+                (0..threads.unwrap_or(0) as usize)
+                    .flat_map(|smt| {
+                        cores
+                            .iter()
+                            .filter_map(move |core| self.core_processors(core).get(smt))
+                    })
+                    .cloned()
+                    .collect()
+            }
+            PlacementPolicy::PerL3 => {
+                // Domains are identified by the index of their first logical processor.
+                let mut domains = vec![];
+                cores.retain(|core| {
+                    let processor = &self.core_processors(core)[0];
+                    let domain = processor
+                        .cache
+                        .l3
+                        .as_ref()
+                        .map_or(processor.package.processor_start, |l3| l3.processor_start);
+
+                    let first = !domains.contains(&domain);
+                    if first {
+                        domains.push(domain);
+                    }
+                    first
+                });
+                self.first_processors(&cores)
+            }
+        }
+    }
+
+    fn first_processors(&self, cores: &[Arc<Core>]) -> Vec<Processor> {
+        cores
+            .iter()
+            .map(|core| self.core_processors(core)[0].clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topology::TestCore;
+    use crate::UarchKind;
+
+    /// Two packages with an L3 cache each, holding a prime core, two performance cores with two
+    /// threads each and two efficiency cores.
+    fn hybrid() -> Topology {
+        let mut cores = vec![];
+        for package in 0..2 {
+            let core = |uarch, class, threads| TestCore {
+                package,
+                class,
+                threads,
+                l3: Some(package),
+                ..TestCore::new(uarch)
+            };
+            cores.push(core(UarchKind::CortexX3, CoreClass::Prime, 1));
+            cores.extend([core(UarchKind::CortexA715, CoreClass::Performance, 2); 2]);
+            cores.extend([core(UarchKind::CortexA510, CoreClass::Efficiency, 1); 2]);
+        }
+        Topology::for_tests(&cores)
+    }
+
+    /// Indices of the `processors` in the topology.
+    fn indices(topology: &Topology, processors: &[Processor]) -> Vec<usize> {
+        processors
+            .iter()
+            .map(|processor| {
+                topology
+                    .processors()
+                    .iter()
+                    .position(|other| {
+                        Arc::ptr_eq(&other.core, &processor.core)
+                            && other.smt_id == processor.smt_id
+                    })
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn per_core() {
+        let topology = hybrid();
+        let placement = topology.placement(PlacementPolicy::PerCore, 0);
+
+        // Prime cores first, then performance and efficiency cores.
+        assert_eq!(
+            indices(&topology, &placement),
+            [0, 7, 1, 3, 8, 10, 5, 6, 12, 13]
+        );
+    }
+
+    #[test]
+    fn per_processor() {
+        let topology = hybrid();
+        let placement = topology.placement(PlacementPolicy::PerProcessor, 0);
+
+        // The second threads of the performance cores come last.
+        assert_eq!(placement.len(), topology.processors().len());
+        assert_eq!(indices(&topology, &placement[10..]), [2, 4, 9, 11]);
+    }
+
+    #[test]
+    fn performance_cores() {
+        let topology = hybrid();
+        let placement = topology.placement(PlacementPolicy::PerformanceCores, 0);
+        assert_eq!(indices(&topology, &placement), [0, 7, 1, 3, 8, 10]);
+
+        // The reservation applies to the performance cores.
+        let placement = topology.placement(PlacementPolicy::PerformanceCores, 2);
+        assert_eq!(indices(&topology, &placement), [0, 7, 1, 3]);
+    }
+
+    #[test]
+    fn performance_cores_without_performance_cores() {
+        let topology = Topology::for_tests(
+            &[TestCore {
+                class: CoreClass::Efficiency,
+                ..TestCore::new(UarchKind::CortexA55)
+            }; 4],
+        );
+
+        let placement = topology.placement(PlacementPolicy::PerformanceCores, 0);
+        assert_eq!(indices(&topology, &placement), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn per_l3() {
+        let topology = hybrid();
+        let placement = topology.placement(PlacementPolicy::PerL3, 0);
+        assert_eq!(indices(&topology, &placement), [0, 7]);
+    }
+
+    #[test]
+    fn per_l3_without_l3() {
+        let cores = (0..2)
+            .flat_map(|package| {
+                [TestCore {
+                    package,
+                    ..TestCore::new(UarchKind::Zen4)
+                }; 3]
+            })
+            .collect::<Vec<_>>();
+        let topology = Topology::for_tests(&cores);
+
+        // Every package is a domain.
+        let placement = topology.placement(PlacementPolicy::PerL3, 0);
+        assert_eq!(indices(&topology, &placement), [0, 3]);
+    }
+
+    #[test]
+    fn reserves_least_preferred_cores() {
+        let topology = hybrid();
+        let placement = topology.placement(PlacementPolicy::PerCore, 4);
+        assert_eq!(indices(&topology, &placement), [0, 7, 1, 3, 8, 10]);
+    }
+
+    #[test]
+    fn keeps_one_core() {
+        let topology = hybrid();
+        for policy in [
+            PlacementPolicy::PerCore,
+            PlacementPolicy::PerProcessor,
+            PlacementPolicy::PerformanceCores,
+            PlacementPolicy::PerL3,
+        ] {
+            let placement = topology.placement(policy, 100);
+            assert_eq!(indices(&topology, &placement), [0], "{policy:?}");
+        }
+    }
+}
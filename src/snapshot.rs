@@ -0,0 +1,311 @@
+use std::sync::Arc;
+
+use crate::{
+    Cache, CacheInfo, Cluster, Core, Die, IsaFeatures, NumaNode, Package, Processor, SnapshotError,
+    Topology, TopologyError, UarchInfo,
+};
+
+/// Everything that [`crate::CpuInfo`] knows about a system, in a form that can be stored and
+/// loaded again with [`CpuInfo::from_snapshot()`](crate::CpuInfo::from_snapshot).
+///
+/// ```no_run
+/// use cpuinfo_rs::{CpuInfo, SystemSnapshot};
+///
+/// let json = CpuInfo::new().snapshot().to_json();
+///
+/// let info = CpuInfo::from_snapshot(SystemSnapshot::from_json(&json).unwrap());
+/// dbg!(info.processors());
+/// ```
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SystemSnapshot {
+    pub(crate) crate_version: String,
+    pub(crate) cpuinfo_version: String,
+    pub(crate) topology: Topology,
+    pub(crate) uarches: Vec<UarchInfo>,
+    pub(crate) isa: IsaFeatures,
+}
+
+impl SystemSnapshot {
+    /// Version of `cpuinfo-rs` that captured the snapshot.
+    pub fn crate_version(&self) -> &str {
+        &self.crate_version
+    }
+
+    /// Git revision of the vendored `cpuinfo` library that captured the snapshot, or `unknown`
+    /// when it was built from a source tree without git metadata.
+    pub fn cpuinfo_version(&self) -> &str {
+        &self.cpuinfo_version
+    }
+
+    pub fn topology(&self) -> &Topology {
+        &self.topology
+    }
+
+    pub fn uarches(&self) -> &[UarchInfo] {
+        &self.uarches
+    }
+
+    pub fn isa(&self) -> &IsaFeatures {
+        &self.isa
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Unable to serialize snapshot")
+    }
+
+    /// Loads a snapshot that was stored with [`SystemSnapshot::to_json()`].
+    ///
+    /// Shared packages, clusters, cores and caches are serialized as copies, so they are linked up
+    /// again through their `*_start` and `*_count` ranges to restore the guarantees of
+    /// [`Topology`]. Snapshots with ranges that don't fit the topology are rejected.
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        serde_json::from_str(json).map_err(SnapshotError::Json)
+    }
+}
+
+/// Finds the item in `items` whose range of children, as returned by `range`, contains `index`.
+pub(crate) fn parent<T>(
+    items: &[Arc<T>],
+    index: usize,
+    range: impl Fn(&T) -> (u32, u32),
+) -> Option<Arc<T>> {
+    items
+        .iter()
+        .find(|item| {
+            let (start, count) = range(item);
+            (start as usize..start as usize + count as usize).contains(&index)
+        })
+        .cloned()
+}
+
+/// Layout in which [`Topology`] is serialized, which is only turned into a [`Topology`] after its
+/// ranges have been validated and linked up.
+#[derive(serde::Deserialize)]
+pub(crate) struct SerializedTopology {
+    packages: Vec<Arc<Package>>,
+    clusters: Vec<Arc<Cluster>>,
+    cores: Vec<Arc<Core>>,
+    processors: Vec<Processor>,
+    l1i: Vec<Arc<Cache>>,
+    l1d: Vec<Arc<Cache>>,
+    l2: Vec<Arc<Cache>>,
+    l3: Vec<Arc<Cache>>,
+    l4: Vec<Arc<Cache>>,
+    max_cache_size: u32,
+    #[serde(default)]
+    dies: Vec<Arc<Die>>,
+    #[serde(default)]
+    numa_nodes: Vec<Arc<NumaNode>>,
+}
+
+impl TryFrom<SerializedTopology> for Topology {
+    type Error = TopologyError;
+
+    fn try_from(topology: SerializedTopology) -> Result<Self, TopologyError> {
+        Self {
+            packages: topology.packages,
+            clusters: topology.clusters,
+            cores: topology.cores,
+            processors: topology.processors,
+            l1i: topology.l1i,
+            l1d: topology.l1d,
+            l2: topology.l2,
+            l3: topology.l3,
+            l4: topology.l4,
+            max_cache_size: topology.max_cache_size,
+            dies: topology.dies,
+            numa_nodes: topology.numa_nodes,
+        }
+        .relink()
+    }
+}
+
+impl Topology {
+    /// Checks that every `*_start` and `*_count` range in the topology is non-empty and within the
+    /// list that it refers to, so that looking up the processors of an item can't panic.
+    pub(crate) fn check_ranges(&self) -> Result<(), TopologyError> {
+        fn check(
+            item: &'static str,
+            index: usize,
+            children: &'static str,
+            (start, count): (u32, u32),
+            len: usize,
+        ) -> Result<(), TopologyError> {
+            if count == 0 || start as u64 + count as u64 > len as u64 {
+                return Err(TopologyError::InvalidRange {
+                    item,
+                    index,
+                    children,
+                });
+            }
+            Ok(())
+        }
+
+        let processors =
+            |item, index, range| check(item, index, "processor", range, self.processors.len());
+        let cores = |item, index, range| check(item, index, "core", range, self.cores.len());
+
+        for (i, p) in self.packages.iter().enumerate() {
+            processors("package", i, (p.processor_start, p.processor_count))?;
+            cores("package", i, (p.core_start, p.core_count))?;
+            let clusters = (p.cluster_start, p.cluster_count);
+            check("package", i, "cluster", clusters, self.clusters.len())?;
+        }
+
+        for (i, d) in self.dies.iter().enumerate() {
+            processors("die", i, (d.processor_start, d.processor_count))?;
+            cores("die", i, (d.core_start, d.core_count))?;
+        }
+
+        for (i, c) in self.clusters.iter().enumerate() {
+            processors("cluster", i, (c.processor_start, c.processor_count))?;
+            cores("cluster", i, (c.core_start, c.core_count))?;
+        }
+
+        for (i, c) in self.cores.iter().enumerate() {
+            processors("core", i, (c.processor_start, c.processor_count))?;
+        }
+
+        for caches in [&self.l1i, &self.l1d, &self.l2, &self.l3, &self.l4] {
+            for (i, c) in caches.iter().enumerate() {
+                processors("cache", i, (c.processor_start, c.processor_count))?;
+            }
+        }
+
+        for (i, node) in self.numa_nodes.iter().enumerate() {
+            for &processor in &node.processors {
+                processors("NUMA node", i, (processor, 1))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces every package, cluster, core and cache that is referenced from the topology with
+    /// the instance in the corresponding list, after checking that their ranges are valid.
+    pub(crate) fn relink(self) -> Result<Self, TopologyError> {
+        self.check_ranges()?;
+
+        let inconsistent = |item, index, parent| TopologyError::MissingParent {
+            item,
+            index,
+            parent,
+        };
+
+        let packages = self.packages;
+
+        let dies = self
+            .dies
+            .iter()
+            .enumerate()
+            .map(|(i, die)| {
+                let mut die = Die::clone(die);
+                die.package = parent(&packages, die.core_start as usize, |p| {
+                    (p.core_start, p.core_count)
+                })
+                .ok_or_else(|| inconsistent("die", i, "package"))?;
+                Ok(Arc::new(die))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let clusters = self
+            .clusters
+            .iter()
+            .enumerate()
+            .map(|(i, cluster)| {
+                let mut cluster = Cluster::clone(cluster);
+                cluster.package = parent(&packages, i, |p| (p.cluster_start, p.cluster_count))
+                    .ok_or_else(|| inconsistent("cluster", i, "package"))?;
+                Ok(Arc::new(cluster))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let processor_nodes = (0..self.processors.len() as u32)
+            .map(|i| {
+                self.numa_nodes
+                    .iter()
+                    .find(|node| node.processors.contains(&i))
+            })
+            .collect::<Vec<_>>();
+        // The node shared by every processor of a range, if there is one.
+        let numa_node = |start: u32, count: u32| {
+            let mut nodes = processor_nodes[start as usize..(start + count) as usize].iter();
+            let first = (*nodes.next()?)?;
+            nodes
+                .all(|node| node.is_some_and(|node| Arc::ptr_eq(node, first)))
+                .then(|| first.clone())
+        };
+
+        let cores = self
+            .cores
+            .iter()
+            .enumerate()
+            .map(|(i, core)| {
+                let mut core = Core::clone(core);
+                core.cluster = parent(&clusters, i, |c| (c.core_start, c.core_count))
+                    .ok_or_else(|| inconsistent("core", i, "cluster"))?;
+                core.package = parent(&packages, i, |p| (p.core_start, p.core_count))
+                    .ok_or_else(|| inconsistent("core", i, "package"))?;
+                core.die = parent(&dies, i, |d| (d.core_start, d.core_count));
+                core.numa_node = numa_node(core.processor_start, core.processor_count);
+                Ok(Arc::new(core))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let [l1i, l1d, l2, l3, l4] =
+            [&self.l1i, &self.l1d, &self.l2, &self.l3, &self.l4].map(|caches| {
+                caches
+                    .iter()
+                    .map(|cache| {
+                        let mut cache = Cache::clone(cache);
+                        cache.numa_node = numa_node(cache.processor_start, cache.processor_count);
+                        Arc::new(cache)
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+        let cache = |caches: &[Arc<Cache>], i| {
+            parent(caches, i, |c| (c.processor_start, c.processor_count))
+        };
+
+        let processors = self
+            .processors
+            .iter()
+            .enumerate()
+            .map(|(i, processor)| {
+                Ok(Processor {
+                    core: parent(&cores, i, |c| (c.processor_start, c.processor_count))
+                        .ok_or_else(|| inconsistent("processor", i, "core"))?,
+                    cluster: parent(&clusters, i, |c| (c.processor_start, c.processor_count))
+                        .ok_or_else(|| inconsistent("processor", i, "cluster"))?,
+                    package: parent(&packages, i, |p| (p.processor_start, p.processor_count))
+                        .ok_or_else(|| inconsistent("processor", i, "package"))?,
+                    cache: CacheInfo {
+                        l1i: cache(&l1i, i),
+                        l1d: cache(&l1d, i),
+                        l2: cache(&l2, i),
+                        l3: cache(&l3, i),
+                        l4: cache(&l4, i),
+                    },
+                    die: parent(&dies, i, |d| (d.processor_start, d.processor_count)),
+                    numa_node: processor_nodes[i].cloned(),
+                    ..processor.clone()
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            packages,
+            dies,
+            clusters,
+            cores,
+            processors,
+            l1i,
+            l1d,
+            l2,
+            l3,
+            l4,
+            ..self
+        })
+    }
+}
@@ -0,0 +1,71 @@
+//! Helpers for reading the text files that Linux exposes in `/proc` and `/sys`.
+
+use std::path::Path;
+
+/// Contents of the file at `path` without surrounding whitespace, or [`None`] if it can't be read.
+///
+/// Files in `/proc` and `/sys` are only looked up in the installed mock while there is one.
+pub(crate) fn read(path: impl AsRef<Path>) -> Option<String> {
+    let path = path.as_ref();
+
+    #[cfg(all(
+        feature = "mock",
+        any(target_os = "linux", target_os = "android"),
+        not(cpuinfo_fallback)
+    ))]
+    if let Some(files) = crate::mock::installed_files() {
+        if path.starts_with("/proc") || path.starts_with("/sys") {
+            let (_, content) = files.iter().find(|(file, _)| Path::new(file) == path)?;
+            return std::str::from_utf8(content)
+                .ok()
+                .map(|s| s.trim().to_string());
+        }
+    }
+
+    std::fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+/// Returns `true` while a [`crate::MockSystem`] replaces the real system, in which case the
+/// passes that execute `cpuid` on the host are skipped.
+pub(crate) fn mocked() -> bool {
+    #[cfg(all(
+        feature = "mock",
+        any(target_os = "linux", target_os = "android"),
+        not(cpuinfo_fallback)
+    ))]
+    let mocked = crate::mock::installed_files().is_some();
+    #[cfg(not(all(
+        feature = "mock",
+        any(target_os = "linux", target_os = "android"),
+        not(cpuinfo_fallback)
+    )))]
+    let mocked = false;
+
+    mocked
+}
+
+/// First value in `/proc/cpuinfo` for any of the given `keys`.
+#[cfg(all(
+    any(target_os = "linux", target_os = "android"),
+    any(cpuinfo_fallback, target_arch = "riscv64", target_arch = "riscv32")
+))]
+pub(crate) fn proc_cpuinfo(keys: &[&str]) -> Option<String> {
+    read("/proc/cpuinfo")?.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        keys.contains(&key.trim()).then(|| value.trim().to_string())
+    })
+}
+
+/// Parses a Linux CPU list such as `0-3,8-11`.
+pub(crate) fn parse_cpu_list(list: &str) -> Option<Vec<i32>> {
+    let mut ids = vec![];
+    for range in list.trim().split(',').filter(|range| !range.is_empty()) {
+        match range.split_once('-') {
+            Some((first, last)) => ids.extend(first.parse::<i32>().ok()?..=last.parse().ok()?),
+            None => ids.push(range.parse().ok()?),
+        }
+    }
+    Some(ids)
+}
@@ -0,0 +1,300 @@
+use std::sync::Arc;
+
+use crate::{Cache, CacheLevel, Cluster, Core, Die, NumaNode, Package, Processor, TopologyError};
+
+/// Snapshot of the processor topology of the system.
+///
+/// Every [`Package`], [`Die`], [`Cluster`], [`Core`] and [`NumaNode`] is allocated once and shared
+/// by everything that references it, so [`Arc::ptr_eq()`] can be used to check whether two
+/// processors are part of the same core, cluster or package.
+///
+/// Deserializing a topology, for example as part of a [`crate::SystemSnapshot`], validates its
+/// ranges and links the shared items up again.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "crate::snapshot::SerializedTopology")]
+pub struct Topology {
+    pub(crate) packages: Vec<Arc<Package>>,
+    pub(crate) clusters: Vec<Arc<Cluster>>,
+    pub(crate) cores: Vec<Arc<Core>>,
+    pub(crate) processors: Vec<Processor>,
+    pub(crate) l1i: Vec<Arc<Cache>>,
+    pub(crate) l1d: Vec<Arc<Cache>>,
+    pub(crate) l2: Vec<Arc<Cache>>,
+    pub(crate) l3: Vec<Arc<Cache>>,
+    pub(crate) l4: Vec<Arc<Cache>>,
+    pub(crate) max_cache_size: u32,
+    #[serde(default)]
+    pub(crate) dies: Vec<Arc<Die>>,
+    #[serde(default)]
+    pub(crate) numa_nodes: Vec<Arc<NumaNode>>,
+}
+
+impl Topology {
+    /// Physical packages (sockets) in the system.
+    pub fn packages(&self) -> &[Arc<Package>] {
+        &self.packages
+    }
+
+    /// Clusters of cores, for example the big and LITTLE clusters of ARM SoCs.
+    pub fn clusters(&self) -> &[Arc<Cluster>] {
+        &self.clusters
+    }
+
+    /// Physical cores in the system.
+    pub fn cores(&self) -> &[Arc<Core>] {
+        &self.cores
+    }
+
+    /// Logical processors (hardware threads) in the system.
+    pub fn processors(&self) -> &[Processor] {
+        &self.processors
+    }
+
+    /// Unique cache instances of the given `level`, these are the same [`Arc`]s that are
+    /// referenced from [`Processor::cache`].
+    pub fn caches(&self, level: CacheLevel) -> &[Arc<Cache>] {
+        match level {
+            CacheLevel::L1i => &self.l1i,
+            CacheLevel::L1d => &self.l1d,
+            CacheLevel::L2 => &self.l2,
+            CacheLevel::L3 => &self.l3,
+            CacheLevel::L4 => &self.l4,
+        }
+    }
+
+    /// Upper bound on the size of any cache in the system, in bytes.
+    pub fn max_cache_size(&self) -> u32 {
+        self.max_cache_size
+    }
+
+    /// Logical processors that share `cache`.
+    pub fn cache_processors(&self, cache: &Cache) -> &[Processor] {
+        self.processor_range(cache.processor_start, cache.processor_count)
+    }
+
+    /// Logical processors (hardware threads) of `core`.
+    pub fn core_processors(&self, core: &Core) -> &[Processor] {
+        self.processor_range(core.processor_start, core.processor_count)
+    }
+
+    /// Logical processors in `cluster`.
+    pub fn cluster_processors(&self, cluster: &Cluster) -> &[Processor] {
+        self.processor_range(cluster.processor_start, cluster.processor_count)
+    }
+
+    /// Logical processors on `die`.
+    pub fn die_processors(&self, die: &Die) -> &[Processor] {
+        self.processor_range(die.processor_start, die.processor_count)
+    }
+
+    /// Logical processors in `package`.
+    pub fn package_processors(&self, package: &Package) -> &[Processor] {
+        self.processor_range(package.processor_start, package.processor_count)
+    }
+
+    /// Fills in everything that `cpuinfo` doesn't report for a topology that was just read from the
+    /// system, then points all processors at the final packages, dies, clusters, cores and nodes.
+    pub(crate) fn complete(self) -> Result<Self, TopologyError> {
+        // The passes look up the processors of every core.
+        self.check_ranges()?;
+
+        // CPUID is read in a single pass, as it requires running on each of the cores.
+        let cpuids = self.core_cpuids();
+        self.with_frequencies(&cpuids)
+            .with_core_classes(&cpuids)
+            .with_capacities()
+            .with_dies(&cpuids)
+            .with_numa_nodes()
+            .relink()
+    }
+
+    fn processor_range(&self, start: u32, count: u32) -> &[Processor] {
+        let start = start as usize;
+        let end = start + count as usize;
+
+        &self.processors[start..end]
+    }
+}
+
+/// Core of a made-up system for unit tests, see [`Topology::for_tests()`].
+#[cfg(test)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TestCore {
+    pub(crate) package: u32,
+    pub(crate) uarch: crate::UarchKind,
+    pub(crate) class: crate::CoreClass,
+    /// Maximum frequency in Hz.
+    pub(crate) frequency: u64,
+    /// Number of logical processors.
+    pub(crate) threads: u32,
+    /// Consecutive cores with the same value share an L3 cache.
+    pub(crate) l3: Option<u32>,
+}
+
+#[cfg(test)]
+impl TestCore {
+    pub(crate) fn new(uarch: crate::UarchKind) -> Self {
+        Self {
+            package: 0,
+            uarch,
+            class: crate::CoreClass::Performance,
+            frequency: 0,
+            threads: 1,
+            l3: None,
+        }
+    }
+}
+
+#[cfg(test)]
+impl Topology {
+    /// Linked topology of made-up `cores`, with a cluster for every run of cores with the same
+    /// package and microarchitecture.
+    ///
+    /// The Linux ID of every processor is its index, as on a system without offline processors.
+    pub(crate) fn for_tests(cores: &[TestCore]) -> Self {
+        use crate::{CacheFlags, CacheInfo, FrequencyInfo, Uarch, Vendor};
+
+        let placeholder = Arc::new(Package {
+            name: String::new(),
+            processor_start: 0,
+            processor_count: 0,
+            core_start: 0,
+            core_count: 0,
+            cluster_start: 0,
+            cluster_count: 0,
+        });
+
+        let mut topology = Self {
+            packages: vec![],
+            dies: vec![],
+            clusters: vec![],
+            cores: vec![],
+            processors: vec![],
+            l1i: vec![],
+            l1d: vec![],
+            l2: vec![],
+            l3: vec![],
+            l4: vec![],
+            max_cache_size: 0,
+            numa_nodes: vec![],
+        };
+        let mut packages: Vec<Package> = vec![];
+        let mut clusters: Vec<Cluster> = vec![];
+        let mut l3: Vec<Cache> = vec![];
+
+        for (i, test) in cores.iter().enumerate() {
+            let previous = i.checked_sub(1).map(|i| &cores[i]);
+            let start = topology.processors.len() as u32;
+            let uarch = Uarch {
+                uarch: test.uarch,
+                name: test.uarch.name().into(),
+            };
+            let vendor = Vendor {
+                vendor: test.uarch.vendor(),
+                name: test.uarch.vendor().name().into(),
+            };
+
+            if previous.map_or(true, |previous| previous.package != test.package) {
+                packages.push(Package {
+                    name: String::new(),
+                    processor_start: start,
+                    processor_count: 0,
+                    core_start: i as u32,
+                    core_count: 0,
+                    cluster_start: clusters.len() as u32,
+                    cluster_count: 0,
+                });
+            }
+            let package = packages.last_mut().unwrap();
+            package.processor_count += test.threads;
+            package.core_count += 1;
+
+            if previous.map_or(true, |previous| {
+                (previous.package, previous.uarch) != (test.package, test.uarch)
+            }) {
+                package.cluster_count += 1;
+                clusters.push(Cluster {
+                    processor_start: start,
+                    processor_count: 0,
+                    core_start: i as u32,
+                    core_count: 0,
+                    cluster_id: package.cluster_count - 1,
+                    package: placeholder.clone(),
+                    vendor: vendor.clone(),
+                    uarch: uarch.clone(),
+                    cpuid: None,
+                    midr: None,
+                    frequency: test.frequency,
+                });
+            }
+            let cluster = clusters.last_mut().unwrap();
+            cluster.processor_count += test.threads;
+            cluster.core_count += 1;
+
+            if test.l3.is_some() {
+                if previous.map_or(true, |previous| previous.l3 != test.l3) {
+                    l3.push(Cache {
+                        size: 32 << 20,
+                        associativity: 16,
+                        sets: 32768,
+                        partitions: 1,
+                        line_size: 64,
+                        flags: CacheFlags::empty(),
+                        processor_start: start,
+                        processor_count: 0,
+                        numa_node: None,
+                    });
+                }
+                l3.last_mut().unwrap().processor_count += test.threads;
+            }
+
+            let core = Arc::new(Core {
+                processor_start: start,
+                processor_count: test.threads,
+                core_id: i as u32,
+                cluster: Arc::new(cluster.clone()),
+                package: placeholder.clone(),
+                vendor,
+                uarch,
+                cpuid: None,
+                midr: None,
+                frequency: test.frequency,
+                class: test.class,
+                die: None,
+                frequency_info: FrequencyInfo {
+                    max: test.frequency,
+                    ..FrequencyInfo::default()
+                },
+                capacity: crate::capacity::MAX_CAPACITY,
+                numa_node: None,
+            });
+            for smt_id in 0..test.threads {
+                topology.processors.push(Processor {
+                    smt_id,
+                    core: core.clone(),
+                    cluster: core.cluster.clone(),
+                    package: placeholder.clone(),
+                    linux_id: Some(topology.processors.len() as i32),
+                    windows_group_id: None,
+                    windows_processor_id: None,
+                    apic_id: None,
+                    cache: CacheInfo {
+                        l1i: None,
+                        l1d: None,
+                        l2: None,
+                        l3: None,
+                        l4: None,
+                    },
+                    die: None,
+                    numa_node: None,
+                });
+            }
+            topology.cores.push(core);
+        }
+
+        topology.packages = packages.into_iter().map(Arc::new).collect();
+        topology.clusters = clusters.into_iter().map(Arc::new).collect();
+        topology.l3 = l3.into_iter().map(Arc::new).collect();
+        topology.relink().unwrap()
+    }
+}
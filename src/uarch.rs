@@ -0,0 +1,398 @@
+// `UarchKind` is generated by `build.rs` from `enum cpuinfo_uarch` in the vendored `cpuinfo.h`.
+include!("uarch_kind.rs");
+
+/// Line of microarchitectures from a single vendor, in which newer designs succeed older ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum UarchFamily {
+    /// Intel Pentium and Quark.
+    IntelP5,
+    /// Intel P6 and its descendants, up to the Core big cores.
+    IntelCore,
+    /// Intel NetBurst (Pentium 4).
+    IntelNetBurst,
+    /// Intel Atom.
+    IntelAtom,
+    /// Intel Xeon Phi.
+    IntelXeonPhi,
+    /// Intel XScale.
+    IntelXScale,
+    /// AMD K5 up to K10.
+    AmdK,
+    /// AMD Bulldozer and its derivatives.
+    AmdBulldozer,
+    /// AMD Zen.
+    AmdZen,
+    /// AMD Geode.
+    AmdGeode,
+    /// AMD Bobcat and its derivatives.
+    AmdCat,
+    /// ARM7, ARM9 and ARM11.
+    ArmClassic,
+    /// ARM Cortex-A cores designed for efficiency, usually the LITTLE cores of big.LITTLE systems.
+    CortexALittle,
+    /// ARM Cortex-A cores designed for performance.
+    CortexABig,
+    /// ARM Cortex-X.
+    CortexX,
+    /// ARM Neoverse N.
+    NeoverseN,
+    /// ARM Neoverse V.
+    NeoverseV,
+    /// ARM Neoverse E.
+    NeoverseE,
+    /// Qualcomm Scorpion, Krait, Kryo and Oryon.
+    Qualcomm,
+    /// Qualcomm Centriq.
+    QualcommCentriq,
+    /// Nvidia Denver and Carmel.
+    NvidiaDenver,
+    /// Samsung Exynos M.
+    SamsungExynos,
+    /// Apple performance cores.
+    ApplePerformance,
+    /// Apple efficiency cores.
+    AppleEfficiency,
+    /// Cavium ThunderX.
+    CaviumThunderX,
+    /// Marvell PJ4.
+    MarvellPj4,
+    /// Broadcom Brahma.
+    BroadcomBrahma,
+    /// Applied Micro X-Gene.
+    ApmXGene,
+    /// HiSilicon TaiShan.
+    HuaweiTaiShan,
+    /// Hygon Dhyana.
+    HygonDhyana,
+}
+
+/// Kind of core a microarchitecture is designed as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum UarchClass {
+    /// General-purpose high performance cores.
+    Big,
+    /// Cores that are paired with big cores to save power, like the LITTLE cores of big.LITTLE
+    /// systems.
+    Efficiency,
+    /// Standalone low-power cores, like Intel Atom.
+    LowPower,
+    /// Cores designed for servers and many-core processors.
+    Server,
+}
+
+/// Members of every [`UarchFamily`], from oldest to newest.
+const FAMILIES: &[(UarchFamily, &[UarchKind])] = {
+    use UarchFamily as F;
+    use UarchKind as U;
+
+    &[
+        (F::IntelP5, &[U::P5, U::Quark]),
+        (
+            F::IntelCore,
+            &[
+                U::P6,
+                U::Dothan,
+                U::Yonah,
+                U::Conroe,
+                U::Penryn,
+                U::Nehalem,
+                U::SandyBridge,
+                U::IvyBridge,
+                U::Haswell,
+                U::Broadwell,
+                U::SkyLake,
+                U::PalmCove,
+                U::SunnyCove,
+            ],
+        ),
+        (F::IntelNetBurst, &[U::Willamette, U::Prescott]),
+        (
+            F::IntelAtom,
+            &[
+                U::Bonnell,
+                U::Saltwell,
+                U::Silvermont,
+                U::Airmont,
+                U::Goldmont,
+                U::GoldmontPlus,
+            ],
+        ),
+        (
+            F::IntelXeonPhi,
+            &[
+                U::KnightsFerry,
+                U::KnightsCorner,
+                U::KnightsLanding,
+                U::KnightsHill,
+                U::KnightsMill,
+            ],
+        ),
+        (F::IntelXScale, &[U::Xscale]),
+        (F::AmdK, &[U::K5, U::K6, U::K7, U::K8, U::K10]),
+        (
+            F::AmdBulldozer,
+            &[U::Bulldozer, U::Piledriver, U::Steamroller, U::Excavator],
+        ),
+        (F::AmdZen, &[U::Zen, U::Zen2, U::Zen3, U::Zen4, U::Zen5]),
+        (F::AmdGeode, &[U::Geode]),
+        (F::AmdCat, &[U::Bobcat, U::Jaguar, U::Puma]),
+        (F::ArmClassic, &[U::Arm7, U::Arm9, U::Arm11]),
+        (
+            F::CortexALittle,
+            &[
+                U::CortexA5,
+                U::CortexA7,
+                U::CortexA32,
+                U::CortexA35,
+                U::CortexA53,
+                U::CortexA55r0,
+                U::CortexA55,
+                U::CortexA65,
+                U::CortexA510,
+                U::CortexA520,
+            ],
+        ),
+        (
+            F::CortexABig,
+            &[
+                U::CortexA8,
+                U::CortexA9,
+                U::CortexA12,
+                U::CortexA15,
+                U::CortexA17,
+                U::CortexA57,
+                U::CortexA72,
+                U::CortexA73,
+                U::CortexA75,
+                U::CortexA76,
+                U::CortexA77,
+                U::CortexA78,
+                U::CortexA710,
+                U::CortexA715,
+                U::CortexA720,
+            ],
+        ),
+        (
+            F::CortexX,
+            &[U::CortexX1, U::CortexX2, U::CortexX3, U::CortexX4],
+        ),
+        (F::NeoverseN, &[U::NeoverseN1, U::NeoverseN2]),
+        (F::NeoverseV, &[U::NeoverseV1, U::NeoverseV2]),
+        (F::NeoverseE, &[U::NeoverseE1]),
+        (F::Qualcomm, &[U::Scorpion, U::Krait, U::Kryo, U::Oryon]),
+        (F::QualcommCentriq, &[U::Falkor, U::Saphira]),
+        (F::NvidiaDenver, &[U::Denver, U::Denver2, U::Carmel]),
+        (
+            F::SamsungExynos,
+            &[
+                U::ExynosM1,
+                U::ExynosM2,
+                U::ExynosM3,
+                U::ExynosM4,
+                U::ExynosM5,
+            ],
+        ),
+        (
+            F::ApplePerformance,
+            &[
+                U::Swift,
+                U::Cyclone,
+                U::Typhoon,
+                U::Twister,
+                U::Hurricane,
+                U::Monsoon,
+                U::Vortex,
+                U::Lightning,
+                U::Firestorm,
+                U::Avalanche,
+            ],
+        ),
+        (
+            F::AppleEfficiency,
+            &[U::Mistral, U::Tempest, U::Thunder, U::Icestorm, U::Blizzard],
+        ),
+        (F::CaviumThunderX, &[U::Thunderx, U::Thunderx2]),
+        (F::MarvellPj4, &[U::Pj4]),
+        (F::BroadcomBrahma, &[U::BrahmaB15, U::BrahmaB53]),
+        (F::ApmXGene, &[U::Xgene]),
+        (F::HuaweiTaiShan, &[U::TaishanV110]),
+        (F::HygonDhyana, &[U::Dhyana]),
+    ]
+};
+
+impl UarchFamily {
+    /// Members of this family known to this crate, from oldest to newest.
+    pub fn members(self) -> &'static [UarchKind] {
+        FAMILIES
+            .iter()
+            .find(|(family, _)| *family == self)
+            .map_or(&[], |(_, members)| members)
+    }
+}
+
+impl UarchKind {
+    /// Vendor that designed this microarchitecture.
+    ///
+    /// This is derived from the upper bits of the raw `cpuinfo` value and may differ from the
+    /// vendor of the processor, for example for Cortex cores in a Qualcomm SoC.
+    pub fn vendor(self) -> crate::VendorKind {
+        use crate::VendorKind as V;
+
+        match self.to_raw() >> 20 {
+            0x001 => V::Intel,
+            0x002 => V::Amd,
+            0x003 => V::Arm,
+            0x004 => V::Qualcomm,
+            0x005 => V::Nvidia,
+            0x006 => V::Samsung,
+            0x007 => V::Apple,
+            0x008 => V::Cavium,
+            0x009 => V::Marvell,
+            0x00a => V::Broadcom,
+            0x00b => V::Apm,
+            0x00c => V::Huawei,
+            0x010 => V::Hygon,
+            _ => V::Unknown,
+        }
+    }
+
+    /// Family this microarchitecture belongs to.
+    pub fn family(self) -> Option<UarchFamily> {
+        self.family_and_generation().map(|(family, _)| family)
+    }
+
+    /// Position of this microarchitecture within its [`UarchFamily`], starting at `0` for the
+    /// oldest member.
+    pub fn generation(self) -> Option<usize> {
+        self.family_and_generation()
+            .map(|(_, generation)| generation)
+    }
+
+    fn family_and_generation(self) -> Option<(UarchFamily, usize)> {
+        FAMILIES.iter().find_map(|(family, members)| {
+            members
+                .iter()
+                .position(|member| *member == self)
+                .map(|generation| (*family, generation))
+        })
+    }
+
+    /// Kind of core this microarchitecture is designed as.
+    pub fn class(self) -> Option<UarchClass> {
+        use UarchFamily as F;
+
+        match self {
+            Self::Quark => return Some(UarchClass::LowPower),
+            Self::BrahmaB53 => return Some(UarchClass::Efficiency),
+            _ => {}
+        }
+
+        Some(match self.family()? {
+            F::IntelP5
+            | F::IntelCore
+            | F::IntelNetBurst
+            | F::AmdK
+            | F::AmdBulldozer
+            | F::AmdZen
+            | F::CortexABig
+            | F::CortexX
+            | F::Qualcomm
+            | F::NvidiaDenver
+            | F::SamsungExynos
+            | F::ApplePerformance
+            | F::MarvellPj4
+            | F::BroadcomBrahma
+            | F::HygonDhyana => UarchClass::Big,
+            F::CortexALittle | F::AppleEfficiency => UarchClass::Efficiency,
+            F::IntelAtom | F::IntelXScale | F::AmdGeode | F::AmdCat | F::ArmClassic => {
+                UarchClass::LowPower
+            }
+            F::IntelXeonPhi
+            | F::NeoverseN
+            | F::NeoverseV
+            | F::NeoverseE
+            | F::QualcommCentriq
+            | F::CaviumThunderX
+            | F::ApmXGene
+            | F::HuaweiTaiShan => UarchClass::Server,
+        })
+    }
+
+    /// Returns `true` if this microarchitecture is `other` or a newer member of the same
+    /// [`UarchFamily`].
+    ///
+    /// Microarchitectures of different families are never ordered, so `Zen 3` is not at least
+    /// `K10`.
+    pub fn is_at_least(self, other: Self) -> bool {
+        match (self.family_and_generation(), other.family_and_generation()) {
+            (Some((family, generation)), Some((other_family, other_generation))) => {
+                family == other_family && generation >= other_generation
+            }
+            _ => self == other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VendorKind;
+
+    #[test]
+    fn every_uarch_has_one_family() {
+        for &uarch in UarchKind::ALL {
+            let families = FAMILIES
+                .iter()
+                .filter(|(_, members)| members.contains(&uarch))
+                .count();
+            let expected = if uarch == UarchKind::Unknown { 0 } else { 1 };
+            assert_eq!(families, expected, "{uarch:?}");
+        }
+    }
+
+    #[test]
+    fn every_uarch_has_a_vendor() {
+        for &uarch in UarchKind::ALL {
+            assert_eq!(
+                uarch.vendor() == VendorKind::Unknown,
+                uarch == UarchKind::Unknown,
+                "{uarch:?}"
+            );
+        }
+        assert_eq!(UarchKind::Zen3.vendor(), VendorKind::Amd);
+        assert_eq!(UarchKind::CortexX3.vendor(), VendorKind::Arm);
+        assert_eq!(UarchKind::Dhyana.vendor(), VendorKind::Hygon);
+    }
+
+    #[test]
+    fn is_at_least() {
+        assert!(UarchKind::Zen4.is_at_least(UarchKind::Zen3));
+        assert!(UarchKind::Zen3.is_at_least(UarchKind::Zen3));
+        assert!(!UarchKind::Zen2.is_at_least(UarchKind::Zen3));
+        assert!(!UarchKind::Zen3.is_at_least(UarchKind::K10));
+        assert!(!UarchKind::CortexX1.is_at_least(UarchKind::CortexA78));
+        assert!(UarchKind::Unknown.is_at_least(UarchKind::Unknown));
+        assert!(!UarchKind::Unknown.is_at_least(UarchKind::Zen));
+    }
+
+    #[test]
+    fn class() {
+        assert_eq!(UarchKind::CortexX3.class(), Some(UarchClass::Big));
+        assert_eq!(UarchKind::CortexA55.class(), Some(UarchClass::Efficiency));
+        assert_eq!(UarchKind::Goldmont.class(), Some(UarchClass::LowPower));
+        assert_eq!(UarchKind::NeoverseV2.class(), Some(UarchClass::Server));
+        assert_eq!(UarchKind::Quark.class(), Some(UarchClass::LowPower));
+        assert_eq!(UarchKind::BrahmaB53.class(), Some(UarchClass::Efficiency));
+        assert_eq!(UarchKind::Unknown.class(), None);
+    }
+
+    #[test]
+    fn generation() {
+        assert_eq!(UarchKind::Zen.generation(), Some(0));
+        assert_eq!(UarchKind::Zen5.generation(), Some(4));
+        assert_eq!(UarchKind::Zen5.family(), Some(UarchFamily::AmdZen));
+        assert_eq!(UarchFamily::CortexX.members()[0], UarchKind::CortexX1);
+    }
+}
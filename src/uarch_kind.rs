@@ -0,0 +1,259 @@
+// Generated by build.rs from `enum cpuinfo_uarch` in cpuinfo.h, do not edit.
+// Regenerate with `cargo build --features generate-bindings`.
+
+cpuinfo_enum! {
+    /// CPU microarchitecture.
+    pub enum UarchKind {
+        #[doc = " Microarchitecture is unknown, or the library failed to get"]
+        #[doc = " information about the microarchitecture from OS"]
+        Unknown = 0x00000000, "unknown";
+        #[doc = " Pentium and Pentium MMX microarchitecture."]
+        P5 = 0x00100100, "P5";
+        #[doc = " Intel Quark microarchitecture."]
+        Quark = 0x00100101, "Quark";
+        #[doc = " Pentium Pro, Pentium II, and Pentium III."]
+        P6 = 0x00100200, "P6";
+        #[doc = " Pentium M."]
+        Dothan = 0x00100201, "Dothan";
+        #[doc = " Intel Core microarchitecture."]
+        Yonah = 0x00100202, "Yonah";
+        #[doc = " Intel Core 2 microarchitecture on 65 nm process."]
+        Conroe = 0x00100203, "Conroe";
+        #[doc = " Intel Core 2 microarchitecture on 45 nm process."]
+        Penryn = 0x00100204, "Penryn";
+        #[doc = " Intel Nehalem and Westmere microarchitectures (Core i3/i5/i7 1st"]
+        #[doc = " gen)."]
+        Nehalem = 0x00100205, "Nehalem";
+        #[doc = " Intel Sandy Bridge microarchitecture (Core i3/i5/i7 2nd gen)."]
+        SandyBridge = 0x00100206, "Sandy Bridge";
+        #[doc = " Intel Ivy Bridge microarchitecture (Core i3/i5/i7 3rd gen)."]
+        IvyBridge = 0x00100207, "Ivy Bridge";
+        #[doc = " Intel Haswell microarchitecture (Core i3/i5/i7 4th gen)."]
+        Haswell = 0x00100208, "Haswell";
+        #[doc = " Intel Broadwell microarchitecture."]
+        Broadwell = 0x00100209, "Broadwell";
+        #[doc = " Intel Sky Lake microarchitecture (14 nm, including"]
+        #[doc = " Kaby/Coffee/Whiskey/Amber/Comet/Cascade/Cooper Lake)."]
+        SkyLake = 0x0010020a, "Sky Lake";
+        #[doc = " Intel Palm Cove microarchitecture (10 nm, Cannon Lake)."]
+        PalmCove = 0x0010020b, "Palm Cove";
+        #[doc = " Intel Sunny Cove microarchitecture (10 nm, Ice Lake)."]
+        SunnyCove = 0x0010020c, "Sunny Cove";
+        #[doc = " Pentium 4 with Willamette, Northwood, or Foster cores."]
+        Willamette = 0x00100300, "Willamette";
+        #[doc = " Pentium 4 with Prescott and later cores."]
+        Prescott = 0x00100301, "Prescott";
+        #[doc = " Intel Atom on 45 nm process."]
+        Bonnell = 0x00100400, "Bonnell";
+        #[doc = " Intel Atom on 32 nm process."]
+        Saltwell = 0x00100401, "Saltwell";
+        #[doc = " Intel Silvermont microarchitecture (22 nm out-of-order Atom)."]
+        Silvermont = 0x00100402, "Silvermont";
+        #[doc = " Intel Airmont microarchitecture (14 nm out-of-order Atom)."]
+        Airmont = 0x00100403, "Airmont";
+        #[doc = " Intel Goldmont microarchitecture (Denverton, Apollo Lake)."]
+        Goldmont = 0x00100404, "Goldmont";
+        #[doc = " Intel Goldmont Plus microarchitecture (Gemini Lake)."]
+        GoldmontPlus = 0x00100405, "Goldmont Plus";
+        #[doc = " Intel Knights Ferry HPC boards."]
+        KnightsFerry = 0x00100500, "Knights Ferry";
+        #[doc = " Intel Knights Corner HPC boards (aka Xeon Phi)."]
+        KnightsCorner = 0x00100501, "Knights Corner";
+        #[doc = " Intel Knights Landing microarchitecture (second-gen MIC)."]
+        KnightsLanding = 0x00100502, "Knights Landing";
+        #[doc = " Intel Knights Hill microarchitecture (third-gen MIC)."]
+        KnightsHill = 0x00100503, "Knights Hill";
+        #[doc = " Intel Knights Mill Xeon Phi."]
+        KnightsMill = 0x00100504, "Knights Mill";
+        #[doc = " Intel/Marvell XScale series."]
+        Xscale = 0x00100600, "XScale";
+        #[doc = " AMD K5."]
+        K5 = 0x00200100, "K5";
+        #[doc = " AMD K6 and alike."]
+        K6 = 0x00200101, "K6";
+        #[doc = " AMD Athlon and Duron."]
+        K7 = 0x00200102, "K7";
+        #[doc = " AMD Athlon 64, Opteron 64."]
+        K8 = 0x00200103, "K8";
+        #[doc = " AMD Family 10h (Barcelona, Istambul, Magny-Cours)."]
+        K10 = 0x00200104, "K10";
+        #[doc = " AMD Bulldozer microarchitecture"]
+        #[doc = " Zambezi FX-series CPUs, Zurich, Valencia and Interlagos Opteron CPUs."]
+        Bulldozer = 0x00200105, "Bulldozer";
+        #[doc = " AMD Piledriver microarchitecture"]
+        #[doc = " Vishera FX-series CPUs, Trinity and Richland APUs, Delhi, Seoul, Abu"]
+        #[doc = " Dhabi Opteron CPUs."]
+        Piledriver = 0x00200106, "Piledriver";
+        #[doc = " AMD Steamroller microarchitecture (Kaveri APUs)."]
+        Steamroller = 0x00200107, "Steamroller";
+        #[doc = " AMD Excavator microarchitecture (Carizzo APUs)."]
+        Excavator = 0x00200108, "Excavator";
+        #[doc = " AMD Zen microarchitecture (12/14 nm Ryzen and EPYC CPUs)."]
+        Zen = 0x00200109, "Zen";
+        #[doc = " AMD Zen 2 microarchitecture (7 nm Ryzen and EPYC CPUs)."]
+        Zen2 = 0x0020010a, "Zen 2";
+        #[doc = " AMD Zen 3 microarchitecture."]
+        Zen3 = 0x0020010b, "Zen 3";
+        #[doc = " AMD Zen 4 microarchitecture."]
+        Zen4 = 0x0020010c, "Zen 4";
+        #[doc = " AMD Zen 5 microarchitecture."]
+        Zen5 = 0x0020010d, "Zen 5";
+        #[doc = " NSC Geode and AMD Geode GX and LX."]
+        Geode = 0x00200200, "Geode";
+        #[doc = " AMD Bobcat mobile microarchitecture."]
+        Bobcat = 0x00200201, "Bobcat";
+        #[doc = " AMD Jaguar mobile microarchitecture."]
+        Jaguar = 0x00200202, "Jaguar";
+        #[doc = " AMD Puma mobile microarchitecture."]
+        Puma = 0x00200203, "Puma";
+        #[doc = " ARM7 series."]
+        Arm7 = 0x00300100, "ARM7";
+        #[doc = " ARM9 series."]
+        Arm9 = 0x00300101, "ARM9";
+        #[doc = " ARM 1136, ARM 1156, ARM 1176, or ARM 11MPCore."]
+        Arm11 = 0x00300102, "ARM11";
+        #[doc = " ARM Cortex-A5."]
+        CortexA5 = 0x00300205, "Cortex-A5";
+        #[doc = " ARM Cortex-A7."]
+        CortexA7 = 0x00300207, "Cortex-A7";
+        #[doc = " ARM Cortex-A8."]
+        CortexA8 = 0x00300208, "Cortex-A8";
+        #[doc = " ARM Cortex-A9."]
+        CortexA9 = 0x00300209, "Cortex-A9";
+        #[doc = " ARM Cortex-A12."]
+        CortexA12 = 0x00300212, "Cortex-A12";
+        #[doc = " ARM Cortex-A15."]
+        CortexA15 = 0x00300215, "Cortex-A15";
+        #[doc = " ARM Cortex-A17."]
+        CortexA17 = 0x00300217, "Cortex-A17";
+        #[doc = " ARM Cortex-A32."]
+        CortexA32 = 0x00300332, "Cortex-A32";
+        #[doc = " ARM Cortex-A35."]
+        CortexA35 = 0x00300335, "Cortex-A35";
+        #[doc = " ARM Cortex-A53."]
+        CortexA53 = 0x00300353, "Cortex-A53";
+        #[doc = " ARM Cortex-A55 revision 0 (restricted dual-issue capabilities"]
+        #[doc = " compared to revision 1+)."]
+        CortexA55r0 = 0x00300354, "Cortex-A55r0";
+        #[doc = " ARM Cortex-A55."]
+        CortexA55 = 0x00300355, "Cortex-A55";
+        #[doc = " ARM Cortex-A57."]
+        CortexA57 = 0x00300357, "Cortex-A57";
+        #[doc = " ARM Cortex-A65."]
+        CortexA65 = 0x00300365, "Cortex-A65";
+        #[doc = " ARM Cortex-A72."]
+        CortexA72 = 0x00300372, "Cortex-A72";
+        #[doc = " ARM Cortex-A73."]
+        CortexA73 = 0x00300373, "Cortex-A73";
+        #[doc = " ARM Cortex-A75."]
+        CortexA75 = 0x00300375, "Cortex-A75";
+        #[doc = " ARM Cortex-A76."]
+        CortexA76 = 0x00300376, "Cortex-A76";
+        #[doc = " ARM Cortex-A77."]
+        CortexA77 = 0x00300377, "Cortex-A77";
+        #[doc = " ARM Cortex-A78."]
+        CortexA78 = 0x00300378, "Cortex-A78";
+        #[doc = " ARM Neoverse N1."]
+        NeoverseN1 = 0x00300400, "Neoverse N1";
+        #[doc = " ARM Neoverse E1."]
+        NeoverseE1 = 0x00300401, "Neoverse E1";
+        #[doc = " ARM Neoverse V1."]
+        NeoverseV1 = 0x00300402, "Neoverse V1";
+        #[doc = " ARM Neoverse N2."]
+        NeoverseN2 = 0x00300403, "Neoverse N2";
+        #[doc = " ARM Neoverse V2."]
+        NeoverseV2 = 0x00300404, "Neoverse V2";
+        #[doc = " ARM Cortex-X1."]
+        CortexX1 = 0x00300501, "Cortex-X1";
+        #[doc = " ARM Cortex-X2."]
+        CortexX2 = 0x00300502, "Cortex-X2";
+        #[doc = " ARM Cortex-X3."]
+        CortexX3 = 0x00300503, "Cortex-X3";
+        #[doc = " ARM Cortex-X4."]
+        CortexX4 = 0x00300504, "Cortex-X4";
+        #[doc = " ARM Cortex-A510."]
+        CortexA510 = 0x00300551, "Cortex-A510";
+        #[doc = " ARM Cortex-A520."]
+        CortexA520 = 0x00300552, "Cortex-A520";
+        #[doc = " ARM Cortex-A710."]
+        CortexA710 = 0x00300571, "Cortex-A710";
+        #[doc = " ARM Cortex-A715."]
+        CortexA715 = 0x00300572, "Cortex-A715";
+        #[doc = " ARM Cortex-A720."]
+        CortexA720 = 0x00300573, "Cortex-A720";
+        #[doc = " Qualcomm Scorpion."]
+        Scorpion = 0x00400100, "Scorpion";
+        #[doc = " Qualcomm Krait."]
+        Krait = 0x00400101, "Krait";
+        #[doc = " Qualcomm Kryo."]
+        Kryo = 0x00400102, "Kryo";
+        #[doc = " Qualcomm Falkor."]
+        Falkor = 0x00400103, "Falkor";
+        #[doc = " Qualcomm Saphira."]
+        Saphira = 0x00400104, "Saphira";
+        #[doc = " Qualcomm Oryon."]
+        Oryon = 0x00400105, "Oryon";
+        #[doc = " Nvidia Denver."]
+        Denver = 0x00500100, "Denver";
+        #[doc = " Nvidia Denver 2."]
+        Denver2 = 0x00500101, "Denver 2";
+        #[doc = " Nvidia Carmel."]
+        Carmel = 0x00500102, "Carmel";
+        #[doc = " Samsung Exynos M1 (Exynos 8890 big cores)."]
+        ExynosM1 = 0x00600100, "Exynos M1";
+        #[doc = " Samsung Exynos M2 (Exynos 8895 big cores)."]
+        ExynosM2 = 0x00600101, "Exynos M2";
+        #[doc = " Samsung Exynos M3 (Exynos 9810 big cores)."]
+        ExynosM3 = 0x00600102, "Exynos M3";
+        #[doc = " Samsung Exynos M4 (Exynos 9820 big cores)."]
+        ExynosM4 = 0x00600103, "Exynos M4";
+        #[doc = " Samsung Exynos M5 (Exynos 9830 big cores)."]
+        ExynosM5 = 0x00600104, "Exynos M5";
+        #[doc = " Apple A6 and A6X processors."]
+        Swift = 0x00700100, "Swift";
+        #[doc = " Apple A7 processor."]
+        Cyclone = 0x00700101, "Cyclone";
+        #[doc = " Apple A8 and A8X processor."]
+        Typhoon = 0x00700102, "Typhoon";
+        #[doc = " Apple A9 and A9X processor."]
+        Twister = 0x00700103, "Twister";
+        #[doc = " Apple A10 and A10X processor."]
+        Hurricane = 0x00700104, "Hurricane";
+        #[doc = " Apple A11 processor (big cores)."]
+        Monsoon = 0x00700105, "Monsoon";
+        #[doc = " Apple A11 processor (little cores)."]
+        Mistral = 0x00700106, "Mistral";
+        #[doc = " Apple A12 processor (big cores)."]
+        Vortex = 0x00700107, "Vortex";
+        #[doc = " Apple A12 processor (little cores)."]
+        Tempest = 0x00700108, "Tempest";
+        #[doc = " Apple A13 processor (big cores)."]
+        Lightning = 0x00700109, "Lightning";
+        #[doc = " Apple A13 processor (little cores)."]
+        Thunder = 0x0070010a, "Thunder";
+        #[doc = " Apple A14 / M1 processor (big cores)."]
+        Firestorm = 0x0070010b, "Firestorm";
+        #[doc = " Apple A14 / M1 processor (little cores)."]
+        Icestorm = 0x0070010c, "Icestorm";
+        #[doc = " Apple A15 / M2 processor (big cores)."]
+        Avalanche = 0x0070010d, "Avalanche";
+        #[doc = " Apple A15 / M2 processor (little cores)."]
+        Blizzard = 0x0070010e, "Blizzard";
+        #[doc = " Cavium ThunderX."]
+        Thunderx = 0x00800100, "ThunderX";
+        #[doc = " Cavium ThunderX2 (originally Broadcom Vulkan)."]
+        Thunderx2 = 0x00800200, "ThunderX2";
+        #[doc = " Marvell PJ4."]
+        Pj4 = 0x00900100, "PJ4";
+        #[doc = " Broadcom Brahma B15."]
+        BrahmaB15 = 0x00a00100, "Brahma B15";
+        #[doc = " Broadcom Brahma B53."]
+        BrahmaB53 = 0x00a00101, "Brahma B53";
+        #[doc = " Applied Micro X-Gene."]
+        Xgene = 0x00b00100, "X-Gene";
+        #[doc = " Applied Micro X-Gene."]
+        Dhyana = 0x01000100, "Dhyana";
+        #[doc = " HiSilicon TaiShan v110 (Huawei Kunpeng 920 series processors)."]
+        TaishanV110 = 0x00c00100, "TaiShan v110";
+    }
+}
@@ -0,0 +1,28 @@
+// `VendorKind` is generated by `build.rs` from `enum cpuinfo_vendor` in the vendored `cpuinfo.h`.
+include!("vendor_kind.rs");
+
+impl VendorKind {
+    /// Vendor of RISC-V cores from the JEDEC manufacturer ID in the `mvendorid` CSR, which Linux
+    /// reports in `/proc/cpuinfo`.
+    ///
+    /// Both backends use this, as `cpuinfo` only reports the vendor of RISC-V cores on kernels
+    /// with `riscv_hwprobe`. Only SiFive (`0x489`) is recognized so far, and the microarchitecture
+    /// is not derived from `marchid`, so [`Uarch::uarch`](crate::Uarch::uarch) stays
+    /// [`UarchKind::Unknown`](crate::UarchKind::Unknown) unless `cpuinfo` knows it.
+    ///
+    /// `/proc/cpuinfo` is read once, as every core of the system has the same vendor.
+    #[cfg(all(
+        any(target_arch = "riscv64", target_arch = "riscv32"),
+        any(target_os = "linux", target_os = "android")
+    ))]
+    pub(crate) fn from_mvendorid() -> Self {
+        static VENDOR: std::sync::OnceLock<VendorKind> = std::sync::OnceLock::new();
+
+        *VENDOR.get_or_init(
+            || match crate::sysfs::proc_cpuinfo(&["mvendorid"]).as_deref() {
+                Some("0x489") => Self::Sifive,
+                _ => Self::Unknown,
+            },
+        )
+    }
+}
@@ -0,0 +1,122 @@
+// Generated by build.rs from `enum cpuinfo_vendor` in cpuinfo.h, do not edit.
+// Regenerate with `cargo build --features generate-bindings`.
+
+cpuinfo_enum! {
+    /// Vendor of a CPU microarchitecture.
+    pub enum VendorKind {
+        #[doc = " Processor vendor is not known to the library, or the library failed"]
+        #[doc = " to get vendor information from the OS."]
+        Unknown = 0x00000000, "unknown";
+        #[doc = " Intel Corporation. Vendor of x86, x86-64, IA64, and ARM processor"]
+        #[doc = " microarchitectures."]
+        #[doc = " "]
+        #[doc = " Sold its ARM design subsidiary in 2006. The last ARM processor design"]
+        #[doc = " was released in 2004."]
+        Intel = 0x00000001, "Intel";
+        #[doc = " Advanced Micro Devices, Inc. Vendor of x86 and x86-64 processor"]
+        #[doc = " microarchitectures."]
+        Amd = 0x00000002, "AMD";
+        #[doc = " ARM Holdings plc. Vendor of ARM and ARM64 processor"]
+        #[doc = " microarchitectures."]
+        Arm = 0x00000003, "ARM";
+        #[doc = " Qualcomm Incorporated. Vendor of ARM and ARM64 processor"]
+        #[doc = " microarchitectures."]
+        Qualcomm = 0x00000004, "Qualcomm";
+        #[doc = " Apple Inc. Vendor of ARM and ARM64 processor microarchitectures."]
+        Apple = 0x00000005, "Apple";
+        #[doc = " Samsung Electronics Co., Ltd. Vendir if ARM64 processor"]
+        #[doc = " microarchitectures."]
+        Samsung = 0x00000006, "Samsung";
+        #[doc = " Nvidia Corporation. Vendor of ARM64-compatible processor"]
+        #[doc = " microarchitectures."]
+        Nvidia = 0x00000007, "Nvidia";
+        #[doc = " MIPS Technologies, Inc. Vendor of MIPS processor microarchitectures."]
+        Mips = 0x00000008, "MIPS";
+        #[doc = " International Business Machines Corporation. Vendor of PowerPC"]
+        #[doc = " processor microarchitectures."]
+        Ibm = 0x00000009, "IBM";
+        #[doc = " Ingenic Semiconductor. Vendor of MIPS processor microarchitectures."]
+        Ingenic = 0x0000000a, "Ingenic";
+        #[doc = " VIA Technologies, Inc. Vendor of x86 and x86-64 processor"]
+        #[doc = " microarchitectures."]
+        #[doc = " "]
+        #[doc = " Processors are designed by Centaur Technology, a subsidiary of VIA"]
+        #[doc = " Technologies."]
+        Via = 0x0000000b, "VIA";
+        #[doc = " Cavium, Inc. Vendor of ARM64 processor microarchitectures."]
+        Cavium = 0x0000000c, "Cavium";
+        #[doc = " Broadcom, Inc. Vendor of ARM processor microarchitectures."]
+        Broadcom = 0x0000000d, "Broadcom";
+        #[doc = " Applied Micro Circuits Corporation (APM). Vendor of ARM64 processor"]
+        #[doc = " microarchitectures."]
+        Apm = 0x0000000e, "Applied Micro";
+        #[doc = " Huawei Technologies Co., Ltd. Vendor of ARM64 processor"]
+        #[doc = " microarchitectures."]
+        #[doc = " "]
+        #[doc = " Processors are designed by HiSilicon, a subsidiary of Huawei."]
+        Huawei = 0x0000000f, "Huawei";
+        #[doc = " Hygon (Chengdu Haiguang Integrated Circuit Design Co., Ltd), Vendor"]
+        #[doc = " of x86-64 processor microarchitectures."]
+        #[doc = " "]
+        #[doc = " Processors are variants of AMD cores."]
+        Hygon = 0x00000010, "Hygon";
+        #[doc = " SiFive, Inc. Vendor of RISC-V processor microarchitectures."]
+        Sifive = 0x00000011, "SiFive";
+        #[doc = " Texas Instruments Inc. Vendor of ARM processor microarchitectures."]
+        TexasInstruments = 0x0000001e, "Texas Instruments";
+        #[doc = " Marvell Technology Group Ltd. Vendor of ARM processor"]
+        #[doc = " microarchitectures."]
+        Marvell = 0x0000001f, "Marvell";
+        #[doc = " RDC Semiconductor Co., Ltd. Vendor of x86 processor"]
+        #[doc = " microarchitectures."]
+        Rdc = 0x00000020, "RDC";
+        #[doc = " DM&P Electronics Inc. Vendor of x86 processor microarchitectures."]
+        Dmp = 0x00000021, "DM&P";
+        #[doc = " Motorola, Inc. Vendor of PowerPC and ARM processor"]
+        #[doc = " microarchitectures."]
+        Motorola = 0x00000022, "Motorola";
+        #[doc = " Transmeta Corporation. Vendor of x86 processor microarchitectures."]
+        #[doc = " "]
+        #[doc = " Now defunct. The last processor design was released in 2004."]
+        #[doc = " Transmeta processors implemented VLIW ISA and used binary translation"]
+        #[doc = " to execute x86 code."]
+        Transmeta = 0x00000032, "Transmeta";
+        #[doc = " Cyrix Corporation. Vendor of x86 processor microarchitectures."]
+        #[doc = " "]
+        #[doc = " Now defunct. The last processor design was released in 1996."]
+        Cyrix = 0x00000033, "Cyrix";
+        #[doc = " Rise Technology. Vendor of x86 processor microarchitectures."]
+        #[doc = " "]
+        #[doc = " Now defunct. The last processor design was released in 1999."]
+        Rise = 0x00000034, "Rise";
+        #[doc = " National Semiconductor. Vendor of x86 processor microarchitectures."]
+        #[doc = " "]
+        #[doc = " Sold its x86 design subsidiary in 1999. The last processor design was"]
+        #[doc = " released in 1998."]
+        Nsc = 0x00000035, "National Semiconductor";
+        #[doc = " Silicon Integrated Systems. Vendor of x86 processor"]
+        #[doc = " microarchitectures."]
+        #[doc = " "]
+        #[doc = " Sold its x86 design subsidiary in 2001. The last processor design was"]
+        #[doc = " released in 2001."]
+        Sis = 0x00000036, "SiS";
+        #[doc = " NexGen. Vendor of x86 processor microarchitectures."]
+        #[doc = " "]
+        #[doc = " Now defunct. The last processor design was released in 1994."]
+        #[doc = " NexGen designed the first x86 microarchitecture which decomposed x86"]
+        #[doc = " instructions into simple microoperations."]
+        Nexgen = 0x00000037, "NexGen";
+        #[doc = " United Microelectronics Corporation. Vendor of x86 processor"]
+        #[doc = " microarchitectures."]
+        #[doc = " "]
+        #[doc = " Ceased x86 in the early 1990s. The last processor design was released"]
+        #[doc = " in 1991. Designed U5C and U5D processors. Both are 486 level."]
+        Umc = 0x00000038, "UMC";
+        #[doc = " Digital Equipment Corporation. Vendor of ARM processor"]
+        #[doc = " microarchitecture."]
+        #[doc = " "]
+        #[doc = " Sold its ARM designs in 1997. The last processor design was released"]
+        #[doc = " in 1997."]
+        Dec = 0x00000039, "DEC";
+    }
+}
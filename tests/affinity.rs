@@ -0,0 +1,54 @@
+// Reads the real system, which the `mock` feature replaces for the whole process.
+#![cfg(not(feature = "mock"))]
+
+use cpuinfo_rs::{CpuInfo, CpuSet, Processor, Topology};
+
+/// Processors that can be part of a [`CpuSet`] on this platform.
+fn identified(processors: &[Processor]) -> impl Iterator<Item = &Processor> {
+    processors
+        .iter()
+        .filter(|processor| processor.linux_id.is_some() || processor.windows_group_id.is_some())
+}
+
+fn assert_set(topology: &Topology, set: &CpuSet, processors: &[Processor]) {
+    assert!(identified(processors).all(|processor| set.contains(processor)));
+    assert_eq!(set.len(), identified(processors).count());
+    assert_eq!(
+        identified(topology.processors())
+            .filter(|processor| set.contains(processor))
+            .count(),
+        set.len()
+    );
+}
+
+#[test]
+fn from_core() {
+    let info = CpuInfo::new();
+    let topology = info.topology();
+
+    for core in topology.cores() {
+        let set = CpuSet::from_core(topology, core);
+        assert_set(topology, &set, topology.core_processors(core));
+    }
+}
+
+#[test]
+fn from_cluster() {
+    let info = CpuInfo::new();
+    let topology = info.topology();
+
+    for cluster in topology.clusters() {
+        let set = CpuSet::from_cluster(topology, cluster);
+        assert_set(topology, &set, topology.cluster_processors(cluster));
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+#[test]
+fn rejects_negative_linux_id() {
+    let mut processor = CpuInfo::new().processors()[0].clone();
+    processor.linux_id = Some(-1);
+
+    let error = CpuSet::from(&processor).pin_current_thread().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+}
@@ -0,0 +1,27 @@
+//! Runs on the ARM Linux targets of the cross CI jobs.
+#![cfg(all(
+    not(feature = "mock"),
+    target_os = "linux",
+    any(target_arch = "aarch64", target_arch = "arm")
+))]
+
+use cpuinfo_rs::CpuInfo;
+
+#[test]
+fn processors_have_linux_ids() {
+    let info = CpuInfo::new();
+    assert!(!info.processors().is_empty());
+
+    for processor in info.processors() {
+        assert!(processor.linux_id.is_some());
+    }
+}
+
+#[test]
+fn cores_have_midr() {
+    let info = CpuInfo::new();
+
+    for core in info.cores() {
+        assert_ne!(core.midr.unwrap().raw(), 0);
+    }
+}
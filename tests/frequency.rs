@@ -0,0 +1,48 @@
+// Reads the real system, which the `mock` feature replaces for the whole process.
+#![cfg(not(feature = "mock"))]
+
+use cpuinfo_rs::{CpuInfo, FrequencyInfo};
+
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/cpufreq");
+
+#[test]
+fn intel_pstate() {
+    let info = FrequencyInfo::from_root(ROOT, 0).unwrap();
+    assert_eq!(
+        info,
+        FrequencyInfo {
+            base: 2_100_000_000,
+            max: 4_700_000_000,
+            min: 800_000_000,
+            governor: Some("powersave".into()),
+        }
+    );
+    assert_eq!(
+        FrequencyInfo::current_from_root(ROOT, 0),
+        Some(3_600_123_000)
+    );
+}
+
+#[test]
+fn without_base_frequency() {
+    let info = FrequencyInfo::from_root(ROOT, 1).unwrap();
+    assert_eq!(info.base, 0);
+    assert_eq!(info.max, 2_841_600_000);
+    assert_eq!(info.min, 844_800_000);
+    assert_eq!(info.governor.as_deref(), Some("schedutil"));
+    assert_eq!(FrequencyInfo::current_from_root(ROOT, 1), None);
+}
+
+#[test]
+fn without_cpufreq() {
+    assert_eq!(FrequencyInfo::from_root(ROOT, 2), None);
+    assert_eq!(FrequencyInfo::from_root(ROOT, 3), None);
+}
+
+#[test]
+fn snapshot_has_no_current_frequency() {
+    let info = CpuInfo::from_snapshot(CpuInfo::new().snapshot());
+    for processor in info.processors() {
+        assert_eq!(info.current_frequency(&processor), None);
+    }
+}
@@ -0,0 +1,48 @@
+// Reads the real system, which the `mock` feature replaces for the whole process.
+#![cfg(not(feature = "mock"))]
+
+use cpuinfo_rs::{CpuInfo, CpuLimits};
+
+fn limits(case: &str) -> CpuLimits {
+    CpuLimits::from_root(format!(
+        "{}/tests/fixtures/cgroup/{case}",
+        env!("CARGO_MANIFEST_DIR")
+    ))
+}
+
+#[test]
+fn v2_unlimited() {
+    let limits = limits("v2-unlimited");
+    assert_eq!(limits.quota(), None);
+    assert_eq!(limits.cpuset(), None);
+}
+
+#[test]
+fn v2_limited() {
+    // The container's cgroup is mounted as the root of the hierarchy.
+    let limits = limits("v2-limited");
+    assert_eq!(limits.quota(), Some(2.0));
+    assert_eq!(
+        limits.cpuset().unwrap().linux_ids(),
+        [0, 1, 2, 3, 8, 9, 10, 11]
+    );
+    assert!(CpuInfo::new().topology().effective_parallelism(&limits) <= 2);
+}
+
+#[test]
+fn v2_nested() {
+    assert_eq!(limits("v2-nested").quota(), Some(1.5));
+}
+
+#[test]
+fn v1() {
+    let limits = limits("v1");
+    assert_eq!(limits.quota(), None);
+    assert_eq!(limits.cpuset().unwrap().linux_ids(), [0, 1, 4]);
+}
+
+#[test]
+fn without_cgroups() {
+    // There is no `none` fixture, like on systems without `/proc/self/cgroup`.
+    assert_eq!(limits("none"), CpuLimits::default());
+}
@@ -0,0 +1,92 @@
+//! Replays a dual-socket AMD EPYC 7742 server, see tests/captures/README.md.
+#![cfg(all(
+    feature = "mock",
+    not(feature = "fallback"),
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "x86")
+))]
+
+use cpuinfo_rs::{CpuInfo, MockCpuid, MockSystem, UarchKind, VendorKind};
+
+const fn cpuid(leaf: u32, subleaf: u32, eax: u32, ebx: u32, ecx: u32, edx: u32) -> MockCpuid {
+    MockCpuid {
+        leaf,
+        subleaf,
+        eax,
+        ebx,
+        ecx,
+        edx,
+    }
+}
+
+/// `cpuid` results of the first logical processor.
+#[rustfmt::skip]
+const CPUID: &[MockCpuid] = &[
+    cpuid(0x00000000, 0, 0x00000010, 0x68747541, 0x444d4163, 0x69746e65),
+    cpuid(0x00000001, 0, 0x00830f10, 0x00800800, 0x7ef8320b, 0x178bfbff),
+    cpuid(0x00000007, 0, 0x00000000, 0x219c01a9, 0x00400004, 0x00000000),
+    cpuid(0x0000000b, 0, 0x00000001, 0x00000002, 0x00000100, 0x00000000),
+    cpuid(0x0000000b, 1, 0x00000007, 0x00000080, 0x00000201, 0x00000000),
+    cpuid(0x0000000b, 2, 0x00000000, 0x00000000, 0x00000002, 0x00000000),
+    cpuid(0x0000000d, 0, 0x00000207, 0x00000340, 0x00000380, 0x00000000),
+    cpuid(0x0000000d, 1, 0x0000000f, 0x00000340, 0x00000000, 0x00000000),
+    cpuid(0x80000000, 0, 0x80000020, 0x68747541, 0x444d4163, 0x69746e65),
+    cpuid(0x80000001, 0, 0x00830f10, 0x40000000, 0x75c237ff, 0x2fd3fbff),
+    cpuid(0x80000002, 0, 0x20444d41, 0x43595045, 0x34373720, 0x34362032),
+    cpuid(0x80000003, 0, 0x726f432d, 0x72502065, 0x7365636f, 0x20726f73),
+    cpuid(0x80000004, 0, 0x20202020, 0x20202020, 0x20202020, 0x00202020),
+    cpuid(0x80000005, 0, 0xff40ff40, 0xff40ff40, 0x20080140, 0x20080140),
+    cpuid(0x80000006, 0, 0x48006400, 0x68006400, 0x02006140, 0x00809140),
+    cpuid(0x80000008, 0, 0x00003030, 0x010cf657, 0x0000707f, 0x00000000),
+    cpuid(0x8000001d, 0, 0x00004121, 0x01c0003f, 0x0000003f, 0x00000000),
+    cpuid(0x8000001d, 1, 0x00004122, 0x01c0003f, 0x0000003f, 0x00000000),
+    cpuid(0x8000001d, 2, 0x00004143, 0x01c0003f, 0x000003ff, 0x00000002),
+    cpuid(0x8000001d, 3, 0x0001c163, 0x03c0003f, 0x00003fff, 0x00000001),
+    cpuid(0x8000001d, 4, 0x00000000, 0x00000000, 0x00000000, 0x00000000),
+    cpuid(0x8000001e, 0, 0x00000000, 0x00000100, 0x00000000, 0x00000000),
+];
+
+#[test]
+fn epyc_7742_2s() {
+    MockSystem::from_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/captures/epyc-7742-2s"
+    ))
+    .unwrap()
+    .with_cpuid(CPUID.iter().copied())
+    .install()
+    .unwrap();
+
+    let info = CpuInfo::new();
+    assert_eq!(info.processors().len(), 256);
+    assert_eq!(info.cores().len(), 128);
+    assert_eq!(info.packages().len(), 2);
+
+    for package in info.packages() {
+        assert_eq!(package.core_count, 64);
+        assert_eq!(package.processor_count, 128);
+        assert!(package.name.contains("7742"), "{}", package.name);
+    }
+
+    for core in info.cores() {
+        assert_eq!(core.vendor.vendor, VendorKind::Amd);
+        assert_eq!(core.uarch.uarch, UarchKind::Zen2);
+        assert_eq!(core.processor_count, 2);
+        assert!(core.numa_node.is_some());
+        assert_eq!(core.frequency_info.max, 2_250_000_000);
+        assert_eq!(core.frequency_info.min, 1_500_000_000);
+        assert_eq!(core.frequency_info.governor.as_deref(), Some("performance"));
+    }
+
+    let nodes = info.numa_nodes();
+    assert_eq!(nodes.len(), 2);
+    for node in &nodes {
+        assert_eq!(node.processors.len(), 128);
+        assert_eq!(node.memory, 263_932_452 * 1024);
+    }
+
+    let isa = info.isa().x86.unwrap();
+    assert!(isa.sse4_2);
+    assert!(isa.sha);
+    assert!(!isa.avx512f);
+}
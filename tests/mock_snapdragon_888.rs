@@ -0,0 +1,58 @@
+//! Replays a Snapdragon 888 phone, see tests/captures/README.md.
+#![cfg(all(
+    feature = "mock",
+    not(feature = "fallback"),
+    target_os = "linux",
+    target_arch = "aarch64"
+))]
+
+use cpuinfo_rs::{CpuInfo, MockSystem, UarchKind, VendorKind};
+
+/// `AT_HWCAP` matching the `Features` line of the captured `/proc/cpuinfo`.
+const HWCAP: u32 = 0x0011_9fff;
+
+#[test]
+fn snapdragon_888() {
+    MockSystem::from_dir(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/captures/snapdragon-888"
+    ))
+    .unwrap()
+    .with_hwcap(HWCAP)
+    .install()
+    .unwrap();
+
+    let info = CpuInfo::new();
+    assert_eq!(info.processors().len(), 8);
+    assert_eq!(info.cores().len(), 8);
+    assert_eq!(info.packages().len(), 1);
+
+    let clusters = info
+        .clusters()
+        .iter()
+        .map(|cluster| (cluster.uarch.uarch, cluster.core_count))
+        .collect::<Vec<_>>();
+    assert_eq!(clusters.len(), 3);
+    assert!(clusters.contains(&(UarchKind::CortexA55, 4)));
+    assert!(clusters.contains(&(UarchKind::CortexA78, 3)));
+    assert!(clusters.contains(&(UarchKind::CortexX1, 1)));
+
+    for core in info.cores() {
+        assert_eq!(core.vendor.vendor, VendorKind::Arm);
+        assert_eq!(core.midr.unwrap().implementer(), 0x41);
+
+        let (max, capacity) = match core.uarch.uarch {
+            UarchKind::CortexA55 => (1_804_800_000, 411),
+            UarchKind::CortexA78 => (2_419_200_000, 869),
+            _ => (2_841_600_000, 1024),
+        };
+        assert_eq!(core.frequency_info.max, max);
+        assert_eq!(core.frequency_info.governor.as_deref(), Some("schedutil"));
+        assert_eq!(core.capacity, capacity);
+    }
+
+    let isa = info.isa().arm.unwrap();
+    assert!(isa.neon);
+    assert!(isa.atomics);
+    assert!(!isa.sve);
+}
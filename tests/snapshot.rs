@@ -0,0 +1,98 @@
+// Reads the real system, which the `mock` feature replaces for the whole process.
+#![cfg(not(feature = "mock"))]
+
+use std::sync::Arc;
+
+use cpuinfo_rs::{CpuInfo, SystemSnapshot, Topology};
+
+fn snapshot_json() -> serde_json::Value {
+    serde_json::from_str(&CpuInfo::new().snapshot().to_json()).unwrap()
+}
+
+fn assert_linked(topology: &Topology) {
+    for processor in topology.processors() {
+        assert!(topology
+            .cores()
+            .iter()
+            .any(|core| Arc::ptr_eq(core, &processor.core)));
+        assert!(topology
+            .clusters()
+            .iter()
+            .any(|cluster| Arc::ptr_eq(cluster, &processor.cluster)));
+        assert!(topology
+            .packages()
+            .iter()
+            .any(|package| Arc::ptr_eq(package, &processor.package)));
+    }
+
+    for core in topology.cores() {
+        assert!(topology
+            .clusters()
+            .iter()
+            .any(|cluster| Arc::ptr_eq(cluster, &core.cluster)));
+    }
+}
+
+#[test]
+fn round_trip() {
+    let json = CpuInfo::new().snapshot().to_json();
+    let snapshot = SystemSnapshot::from_json(&json).unwrap();
+
+    assert_eq!(snapshot.to_json(), json);
+    assert_linked(snapshot.topology());
+
+    let info = CpuInfo::from_snapshot(snapshot);
+    assert_eq!(info.processors().len(), CpuInfo::new().processors().len());
+    assert!(info.current_processor().is_none());
+}
+
+#[test]
+fn topology_is_linked_when_deserialized() {
+    let json = serde_json::to_string(CpuInfo::new().topology()).unwrap();
+    let topology: Topology = serde_json::from_str(&json).unwrap();
+
+    assert_linked(&topology);
+}
+
+#[test]
+fn rejects_out_of_bounds_range() {
+    let mut json = snapshot_json();
+    json["topology"]["cores"][0]["processor_count"] = 100_000.into();
+
+    let error = SystemSnapshot::from_json(&json.to_string()).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("invalid processor range for core 0"));
+}
+
+#[test]
+fn rejects_overflowing_range() {
+    let mut json = snapshot_json();
+    json["topology"]["packages"][0]["core_start"] = u32::MAX.into();
+
+    let error = SystemSnapshot::from_json(&json.to_string()).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("invalid core range for package 0"));
+}
+
+#[test]
+fn rejects_empty_range() {
+    let mut json = snapshot_json();
+    json["topology"]["clusters"][0]["core_count"] = 0.into();
+
+    assert!(SystemSnapshot::from_json(&json.to_string()).is_err());
+}
+
+#[test]
+fn rejects_processor_without_core() {
+    let mut json = snapshot_json();
+    let processors = json["topology"]["processors"].as_array_mut().unwrap();
+    let index = processors.len();
+    processors.push(processors[0].clone());
+
+    let error = SystemSnapshot::from_json(&json.to_string()).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains(&format!("no core containing processor {index}")));
+}